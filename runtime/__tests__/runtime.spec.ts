@@ -2,7 +2,7 @@ import path from 'node:path';
 import { transform } from '@swc/core';
 import { faker } from '@faker-js/faker';
 import '../index';
-import type { CommonJsContext } from '../types';
+import type { CommonJsContext, ModuleMeta } from '../types';
 
 const SNAPSHOT_TEST_CODE_INPUT_ESM = `
 import React, { useState, useEffect } from 'react';
@@ -74,6 +74,8 @@ const transformWithPlugin = async (code: string, moduleId?: string) => {
   return result.code;
 };
 
+const EMPTY_META: ModuleMeta = { deps: [], externals: [] };
+
 const generateModuleId = () => {
   return path.join(faker.system.directoryPath(), faker.word.noun() + '.js');
 };
@@ -112,9 +114,40 @@ describe('swc-plugin-global-module/runtime', () => {
     expect(typeof global.__modules.cjs === 'function').toEqual(true);
     expect(typeof global.__modules.import === 'function').toEqual(true);
     expect(typeof global.__modules.require === 'function').toEqual(true);
+    expect(typeof global.__modules.dependents === 'function').toEqual(true);
+    expect(typeof global.__modules.hot === 'function').toEqual(true);
+    expect(typeof global.__modules.update === 'function').toEqual(true);
+    expect(typeof global.__modules.isRefreshBoundary === 'function').toEqual(true);
     expect(typeof global.__modules.helpers === 'object').toEqual(true);
   });
 
+  describe('when namespace is configured', () => {
+    afterEach(() => {
+      delete global.__GLOBAL_MODULE_NAMESPACE__;
+    });
+
+    it('should expose apis to the namespace', () => {
+      global.__GLOBAL_MODULE_NAMESPACE__ = '__app_modules';
+      jest.isolateModules(() => require('../index'));
+      expect(typeof global.__app_modules.esm === 'function').toEqual(true);
+      expect(global.__app_modules).not.toBe(global.__modules);
+    });
+  });
+
+  describe('when global object is configured', () => {
+    afterEach(() => {
+      delete global.__GLOBAL_MODULE_OBJECT__;
+      delete global.app;
+    });
+
+    it('should expose apis to the object of the path', () => {
+      global.__GLOBAL_MODULE_OBJECT__ = 'window.app';
+      jest.isolateModules(() => require('../index'));
+      expect(typeof global.app.__modules.esm === 'function').toEqual(true);
+      expect(global.app.__modules).not.toBe(global.__modules);
+    });
+  });
+
   describe('when trying to get unregistered module', () => {
     it('should throw error', () => {
       expect(() => {
@@ -179,7 +212,7 @@ describe('swc-plugin-global-module/runtime', () => {
       beforeEach(() => {
         namedExportKey = faker.string.alpha(10);
         reExportModule = { [namedExportKey]: exportValue };
-        global.__modules.esm(moduleId, {}, reExportModule);
+        global.__modules.esm(moduleId, {}, EMPTY_META, reExportModule);
       });
 
       describe('when call `import()` to get registered module', () => {
@@ -192,7 +225,7 @@ describe('swc-plugin-global-module/runtime', () => {
       describe('when module that contains `default` property to be re-exported', () => {
         beforeEach(() => {
           reExportModule = { default: exportValue };
-          global.__modules.esm(moduleId, {}, reExportModule);
+          global.__modules.esm(moduleId, {}, EMPTY_META, reExportModule);
         });
 
         describe('when call `import()` to get registered module', () => {
@@ -325,6 +358,286 @@ describe('swc-plugin-global-module/runtime', () => {
     });
   });
 
+  describe('dependencies', () => {
+    let moduleId: string;
+    let dependencyId: string;
+
+    beforeEach(() => {
+      moduleId = generateModuleId();
+      dependencyId = generateModuleId();
+    });
+
+    describe('when register ES module with dependencies', () => {
+      beforeEach(() => {
+        global.__modules.esm(moduleId, {}, {
+          deps: [dependencyId],
+          externals: ['react'],
+        });
+      });
+
+      describe('when call `dependents()` to get dependent modules', () => {
+        it('should returns ids of the dependent modules', () => {
+          expect(global.__modules.dependents(dependencyId)).toEqual([moduleId]);
+        });
+      });
+    });
+
+    describe('when register CommonJS module with dependencies', () => {
+      beforeEach(() => {
+        global.__modules.cjs(moduleId, {
+          deps: [dependencyId],
+          externals: [],
+        });
+      });
+
+      describe('when call `dependents()` to get dependent modules', () => {
+        it('should returns ids of the dependent modules', () => {
+          expect(global.__modules.dependents(dependencyId)).toEqual([moduleId]);
+        });
+      });
+    });
+  });
+
+  describe('hot module replacement', () => {
+    let moduleId: string;
+    let dependencyId: string;
+
+    beforeEach(() => {
+      moduleId = generateModuleId();
+      dependencyId = generateModuleId();
+    });
+
+    it('should returns the same hot context of the module', () => {
+      expect(global.__modules.hot(moduleId)).toBe(global.__modules.hot(moduleId));
+    });
+
+    describe('when the module accepts itself', () => {
+      let acceptCallback: jest.Mock;
+      let disposeCallback: jest.Mock;
+
+      beforeEach(() => {
+        acceptCallback = jest.fn();
+        disposeCallback = jest.fn((data) => {
+          data.count = 1;
+        });
+        global.__modules.esm(moduleId, { count: 0 }, EMPTY_META);
+        global.__modules.hot(moduleId).accept(acceptCallback);
+        global.__modules.hot(moduleId).dispose(disposeCallback);
+      });
+
+      describe('when call `update()` to apply the update', () => {
+        it('should call callbacks and pass the data to the updated module', () => {
+          const accepted = global.__modules.update(moduleId, () => {
+            const { data } = global.__modules.hot(moduleId);
+            global.__modules.esm(moduleId, { count: data.count }, EMPTY_META);
+          });
+
+          expect(accepted).toEqual(true);
+          expect(disposeCallback).toHaveBeenCalledTimes(1);
+          expect(acceptCallback).toHaveBeenCalledWith(
+            expect.objectContaining({ count: 1 }),
+          );
+        });
+      });
+
+      describe('when the updated module is invalidated', () => {
+        it('should not accept the update', () => {
+          const accepted = global.__modules.update(moduleId, () => {
+            global.__modules.esm(moduleId, {}, EMPTY_META);
+            global.__modules.hot(moduleId).invalidate();
+          });
+
+          expect(accepted).toEqual(false);
+        });
+      });
+    });
+
+    describe('when the dependent module accepts the dependency', () => {
+      let acceptCallback: jest.Mock;
+
+      beforeEach(() => {
+        acceptCallback = jest.fn();
+        global.__modules.esm(dependencyId, {}, EMPTY_META);
+        global.__modules.esm(moduleId, {}, {
+          deps: [dependencyId],
+          externals: [],
+        });
+        global.__modules.hot(moduleId).accept([dependencyId], acceptCallback);
+      });
+
+      describe('when call `update()` to apply the update of the dependency', () => {
+        it('should call the accept callback of the dependent module', () => {
+          const accepted = global.__modules.update(dependencyId, () => {
+            global.__modules.esm(dependencyId, { updated: true }, EMPTY_META);
+          });
+
+          expect(accepted).toEqual(true);
+          expect(acceptCallback).toHaveBeenCalledWith(
+            expect.objectContaining({ updated: true }),
+          );
+        });
+      });
+    });
+
+    describe('when the module is a component boundary', () => {
+      it('should accept the update', () => {
+        const meta: ModuleMeta = { ...EMPTY_META, boundary: 'component' };
+        global.__modules.esm(moduleId, {}, meta);
+
+        expect(
+          global.__modules.update(moduleId, () => {
+            global.__modules.esm(moduleId, {}, meta);
+          }),
+        ).toEqual(true);
+      });
+    });
+
+    describe('when the update does not change the version of the module', () => {
+      it('should accept the update', () => {
+        const meta: ModuleMeta = { ...EMPTY_META, version: 'a1b2c3d4e5f60718' };
+        global.__modules.esm(moduleId, {}, meta);
+
+        expect(
+          global.__modules.update(moduleId, () => {
+            global.__modules.esm(moduleId, {}, meta);
+          }),
+        ).toEqual(true);
+      });
+    });
+
+    describe('when no modules accept the update', () => {
+      it('should not accept the update', () => {
+        global.__modules.esm(moduleId, {}, EMPTY_META);
+
+        expect(global.__modules.update(moduleId, () => {})).toEqual(false);
+      });
+    });
+  });
+
+  describe('module definition', () => {
+    let moduleId: string;
+    let dependencyId: string;
+    let factory: jest.Mock;
+
+    beforeEach(() => {
+      moduleId = generateModuleId();
+      dependencyId = generateModuleId();
+      factory = jest.fn((require, exports) => {
+        const { value } = require(dependencyId);
+        exports({ value });
+      });
+      global.__modules.define(dependencyId, EMPTY_META, (_require, exports) => {
+        exports({ value: 1 });
+      });
+      global.__modules.define(moduleId, { deps: [dependencyId], externals: [] }, factory);
+    });
+
+    it('should not evaluate the module until it is imported', () => {
+      expect(factory).not.toHaveBeenCalled();
+      expect(global.__modules.import(moduleId)).toEqual(expect.objectContaining({ value: 1 }));
+      expect(global.__modules.import(moduleId)).toEqual(expect.objectContaining({ value: 1 }));
+      expect(factory).toHaveBeenCalledTimes(1);
+    });
+
+    describe('when define the CommonJS module', () => {
+      it('should register the module with `exports.cjs()`', () => {
+        global.__modules.define(moduleId, EMPTY_META, (_require, exports) => {
+          const context = exports.cjs();
+          context.exports.default = 'cjs';
+        });
+
+        expect(global.__modules.require(moduleId)).toEqual('cjs');
+      });
+    });
+
+    describe('when require the CommonJS module in the factory', () => {
+      it('should return the module with `require.cjs()`', () => {
+        global.__modules.define(dependencyId, EMPTY_META, (_require, exports) => {
+          exports.cjs().exports.default = 'cjs';
+        });
+        global.__modules.define(moduleId, EMPTY_META, (require, exports, _hot, module) => {
+          module.exports = require.cjs(dependencyId);
+          exports({ value: module.exports });
+        });
+
+        expect(global.__modules.import(moduleId)).toEqual(
+          expect.objectContaining({ value: 'cjs' }),
+        );
+      });
+    });
+
+    describe('when define the evaluated module with the same version', () => {
+      it('should skip the update', () => {
+        const meta: ModuleMeta = { ...EMPTY_META, version: 'a1b2c3d4e5f60718' };
+        const versionedFactory = jest.fn();
+        global.__modules.define(moduleId, meta, versionedFactory);
+        global.__modules.import(moduleId);
+
+        expect(global.__modules.define(moduleId, meta, versionedFactory)).toEqual(true);
+        expect(versionedFactory).toHaveBeenCalledTimes(1);
+      });
+    });
+
+    describe('when define the evaluated module again', () => {
+      it('should re-execute the module as an update', () => {
+        const acceptCallback = jest.fn();
+        global.__modules.import(moduleId);
+        global.__modules.hot(moduleId).accept(acceptCallback);
+
+        const accepted = global.__modules.define(moduleId, EMPTY_META, (_require, exports) => {
+          exports({ value: 2 });
+        });
+
+        expect(accepted).toEqual(true);
+        expect(acceptCallback).toHaveBeenCalledWith(
+          expect.objectContaining({ value: 2 }),
+        );
+      });
+    });
+  });
+
+  describe('react refresh', () => {
+    let moduleId: string;
+
+    beforeEach(() => {
+      moduleId = generateModuleId();
+      global.__ReactRefresh = {
+        isLikelyComponentType: (value: unknown) => {
+          return typeof value === 'function' && /^[A-Z]/.test(value.name);
+        },
+      };
+    });
+
+    afterEach(() => {
+      delete global.__ReactRefresh;
+    });
+
+    describe('when all exports of the module are components', () => {
+      it('should be a refresh boundary', () => {
+        global.__modules.esm(moduleId, { App: function App() {} }, EMPTY_META);
+
+        expect(global.__modules.isRefreshBoundary(moduleId)).toEqual(true);
+      });
+    });
+
+    describe('when the module exports non-component values', () => {
+      it('should not be a refresh boundary', () => {
+        global.__modules.esm(moduleId, {
+          App: function App() {},
+          formatCount: function formatCount() {},
+        }, EMPTY_META);
+
+        expect(global.__modules.isRefreshBoundary(moduleId)).toEqual(false);
+      });
+    });
+
+    describe('when the module is not registered', () => {
+      it('should not be a refresh boundary', () => {
+        expect(global.__modules.isRefreshBoundary(moduleId)).toEqual(false);
+      });
+    });
+  });
+
   describe('external modules', () => {
     const MODULE_ID = 'external';
 
@@ -363,5 +676,58 @@ describe('swc-plugin-global-module/runtime', () => {
         });
       });
     });
+
+    describe('when register multiple versions of the module', () => {
+      const VERSIONED_MODULE_ID = 'versioned-external';
+
+      beforeEach(() => {
+        global.__modules.external(VERSIONED_MODULE_ID, { version: 17 }, { version: '17.0.2' });
+        global.__modules.external(VERSIONED_MODULE_ID, { version: 18 }, { version: '18.2.0' });
+        global.__modules.external(VERSIONED_MODULE_ID, { version: 16 }, { version: '16.14.0' });
+      });
+
+      describe('when call `external()` with required version', () => {
+        it('should returns the highest module that satisfies the range', () => {
+          expect(
+            global.__modules.external(VERSIONED_MODULE_ID, undefined, {
+              requiredVersion: '^17.0.0 || ^18.0.0',
+            }).version,
+          ).toEqual(18);
+          expect(
+            global.__modules.external(VERSIONED_MODULE_ID, undefined, {
+              requiredVersion: '>=16.8 <18',
+            }).version,
+          ).toEqual(17);
+        });
+
+        it('should throw error if no versions satisfy the range', () => {
+          expect(() => {
+            global.__modules.external(VERSIONED_MODULE_ID, undefined, {
+              requiredVersion: '^19.0.0',
+            });
+          }).toThrow(/not found/);
+        });
+      });
+
+      describe('when call `external()` without required version', () => {
+        it('should returns the last registered module', () => {
+          expect(global.__modules.external(VERSIONED_MODULE_ID).version).toEqual(16);
+        });
+      });
+    });
+
+    describe('when wrap global variable with `asModule` helper', () => {
+      it('should provide the value as `default` property', () => {
+        const globalValue = { version: faker.string.uuid() };
+        const wrappedModule = global.__modules.helpers.asModule(globalValue);
+        expect(wrappedModule.default).toEqual(globalValue);
+        expect(wrappedModule.version).toEqual(globalValue.version);
+      });
+
+      it('should returns ES module as is', () => {
+        const esModule = { __esModule: true, default: faker.string.uuid() };
+        expect(global.__modules.helpers.asModule(esModule)).toBe(esModule);
+      });
+    });
   });
 });
@@ -0,0 +1,27 @@
+import { satisfies } from '../semver';
+
+describe('swc-plugin-global-module/runtime/semver', () => {
+  it.each([
+    ['18.2.0', '^18.0.0'],
+    ['0.2.5', '^0.2.3'],
+    ['1.2.9', '~1.2.3'],
+    ['17.0.2', '>=16.8 <18 || ^19'],
+    ['1.5.0', '1.x'],
+    ['1.5.0', '1.0.0 - 2.0.0'],
+    ['3.0.0', '*'],
+  ])('%s should satisfy %s', (version, range) => {
+    expect(satisfies(version, range)).toEqual(true);
+  });
+
+  it.each([
+    ['19.0.0', '^18.0.0'],
+    ['18.0.0-rc.1', '^18.0.0'],
+    ['0.3.0', '^0.2.3'],
+    ['1.3.0', '~1.2.3'],
+    ['18.0.0', '>=16.8 <18 || ^19'],
+    ['2.0.1', '1.0.0 - 2.0.0'],
+    ['invalid', '*'],
+  ])('%s should not satisfy %s', (version, range) => {
+    expect(satisfies(version, range)).toEqual(false);
+  });
+});
@@ -1,11 +1,39 @@
 import { obj, createModuleRegistry } from './helpers';
-import type { GlobalModule, GlobalModuleApi } from './types';
+import { compareVersions, parseVersion, satisfies } from './semver';
+import type {
+  ExternalVersion,
+  GlobalModule,
+  GlobalModuleApi,
+  GlobalModuleId,
+  HotData,
+  HotState,
+  ModuleDefinition,
+  ModuleExports,
+  ModuleMeta,
+  ModuleRequire,
+} from './types';
 
 ((global) => {
   if (typeof global === 'undefined') {
     throw new Error('[Global Module] `global` is undefined');
   }
 
+  // Property name of the registry (`namespace` option of the plugin).
+  const namespace: string = (global as any).__GLOBAL_MODULE_NAMESPACE__ ?? '__modules';
+
+  // Root object of the registry (`globalObject` option of the plugin, eg. `window.app`).
+  // Each segment is looked up from the global object, and missing objects are created.
+  const globalObject = (
+    ((global as any).__GLOBAL_MODULE_OBJECT__ ?? 'global') as string
+  )
+    .split('.')
+    .reduce((target, key, index) => {
+      if (index === 0 && ['global', 'globalThis', 'self', 'window'].includes(key)) {
+        return key in target ? target[key] : target;
+      }
+      return (target[key] ??= {});
+    }, global as any);
+
   const __defProp = Object.defineProperty;
   const __getOwnPropNames = Object.getOwnPropertyNames;
   const __getOwnPropDesc = Object.getOwnPropertyDescriptor;
@@ -33,43 +61,236 @@ import type { GlobalModule, GlobalModuleApi } from './types';
 
   const registry = createModuleRegistry();
   const externalRegistry = createModuleRegistry();
+  const externalVersions: Record<string, ExternalVersion[]> = Object.create(null);
+  const metaRegistry: Record<GlobalModuleId, ModuleMeta> = Object.create(null);
+  const hotRegistry: Record<GlobalModuleId, HotState> = Object.create(null);
+  const definitionRegistry: Record<GlobalModuleId, ModuleDefinition> = Object.create(null);
+  const evaluatingModules = new Set<GlobalModuleId>();
+
+  const createHotState = (data: HotData = {}): HotState => {
+    const state: HotState = {
+      context: {
+        data,
+        accept: (deps, callback) => {
+          if (typeof deps === 'undefined' || typeof deps === 'function') {
+            state.selfAccepted = true;
+            deps && state.acceptCallbacks.push(deps);
+            return;
+          }
+          (Array.isArray(deps) ? deps : [deps]).forEach((dependencyId) => {
+            (state.depAcceptCallbacks[dependencyId] ??= []).push(
+              callback ?? (() => {}),
+            );
+          });
+        },
+        dispose: (callback) => {
+          state.disposeCallbacks.push(callback);
+        },
+        invalidate: () => {
+          state.invalidated = true;
+        },
+      },
+      selfAccepted: false,
+      invalidated: false,
+      acceptCallbacks: [],
+      depAcceptCallbacks: Object.create(null),
+      disposeCallbacks: [],
+    };
+    return state;
+  };
+
+  const evaluate = (moduleId: GlobalModuleId) => {
+    const definition = definitionRegistry[moduleId];
+    const require: ModuleRequire = Object.assign((id: GlobalModuleId) => load(id), {
+      cjs: (id: GlobalModuleId) => globalModuleApi.require(id),
+    });
+    const exports: ModuleExports = Object.assign(
+      (exportedModule: GlobalModule, ...reExportedModules: GlobalModule[]) => {
+        globalModuleApi.esm(moduleId, exportedModule, definition.meta, ...reExportedModules);
+      },
+      { cjs: () => globalModuleApi.cjs(moduleId, definition.meta) },
+    );
+
+    // Circular dependencies get the module that is being evaluated.
+    evaluatingModules.add(moduleId);
+    try {
+      definition.factory(require, exports, globalModuleApi.hot(moduleId), { exports: {} });
+      definition.evaluated = true;
+    } finally {
+      evaluatingModules.delete(moduleId);
+    }
+  };
+
+  // Evaluates the defined module if it's not evaluated yet.
+  const load = (moduleId: GlobalModuleId) => {
+    const definition = definitionRegistry[moduleId];
+    if (definition && !definition.evaluated && !evaluatingModules.has(moduleId)) {
+      evaluate(moduleId);
+    }
+    return registry[moduleId];
+  };
+
   const globalModuleApi: GlobalModuleApi = {
     __registry: registry,
     __externalRegistry: externalRegistry,
-    esm: (moduleId, exportedModule, ...reExportedModules) => {
+    __externalVersions: externalVersions,
+    __metaRegistry: metaRegistry,
+    __hotRegistry: hotRegistry,
+    __definitionRegistry: definitionRegistry,
+    esm: (moduleId, exportedModule, meta, ...reExportedModules) => {
       const esModule = __copyProps(obj(exportedModule), exportedModule);
       reExportedModules.forEach((reExportedModule) => {
         __copyProps(esModule, reExportedModule, 'default');
       });
       registry[moduleId] = esModule;
+      if (meta) {
+        metaRegistry[moduleId] = meta;
+      }
     },
-    cjs: (moduleId) => {
+    cjs: (moduleId, meta) => {
       const commonJsModule = (registry[moduleId] = __defProp(obj(), '__cjs', {
         enumerable: true,
         value: true,
       }));
+      if (meta) {
+        metaRegistry[moduleId] = meta;
+      }
       return { exports: commonJsModule };
     },
-    external: (source, externalModule) => {
-      return typeof externalModule !== 'undefined'
-        ? externalRegistry[source] = externalModule
-        : externalRegistry[source];
+    define: (moduleId, meta, factory) => {
+      const prevDefinition = definitionRegistry[moduleId];
+      // Skip the update if the module is not changed.
+      if (
+        prevDefinition?.evaluated &&
+        meta.version &&
+        meta.version === prevDefinition.meta.version
+      ) {
+        return true;
+      }
+
+      definitionRegistry[moduleId] = { meta, factory, evaluated: false };
+      metaRegistry[moduleId] = meta;
+
+      if (prevDefinition?.evaluated) {
+        return globalModuleApi.update(moduleId, () => evaluate(moduleId));
+      }
+      return true;
     },
-    import: (moduleId) => registry[moduleId],
+    external: (source, externalModule, options) => {
+      if (typeof externalModule !== 'undefined') {
+        if (options?.version) {
+          (externalVersions[source] ??= []).push({
+            version: options.version,
+            module: externalModule,
+          });
+        }
+        return externalRegistry[source] = externalModule;
+      }
+
+      const versions = externalVersions[source];
+      const requiredVersion = options?.requiredVersion;
+      if (!requiredVersion || !versions) {
+        return externalRegistry[source];
+      }
+
+      // The highest version that satisfies the required range.
+      const matched = versions
+        .filter(({ version }) => satisfies(version, requiredVersion))
+        .sort((a, b) => compareVersions(parseVersion(b.version)!, parseVersion(a.version)!))[0];
+
+      if (!matched) {
+        throw new Error(
+          `external module '${source}@${requiredVersion}' not found (registered: ${versions
+            .map(({ version }) => version)
+            .join(', ')})`,
+        );
+      }
+      return matched.module;
+    },
+    import: (moduleId) => load(moduleId),
     require: (moduleId) => {
-      const targetModule = registry[moduleId];
+      const targetModule = load(moduleId);
       return targetModule.__cjs
         ? targetModule.default ?? targetModule
         : targetModule;
     },
+    dependents: (moduleId) => {
+      return Object.keys(metaRegistry).filter((id) => {
+        return metaRegistry[id]?.deps.includes(moduleId);
+      });
+    },
+    hot: (moduleId) => {
+      return (hotRegistry[moduleId] ??= createHotState()).context;
+    },
+    update: (moduleId, evaluate) => {
+      const prevState = hotRegistry[moduleId];
+      const prevVersion = metaRegistry[moduleId]?.version;
+      const data: HotData = {};
+      prevState?.disposeCallbacks.forEach((callback) => callback(data));
+      const state = (hotRegistry[moduleId] = createHotState(data));
+
+      evaluate();
+
+      // No-op updates don't need to be accepted.
+      if (prevVersion && metaRegistry[moduleId]?.version === prevVersion) {
+        return !state.invalidated;
+      }
+
+      // Accept callbacks of the previous module instance are called with the updated module.
+      const updatedModule = registry[moduleId];
+      if (prevState?.selfAccepted) {
+        prevState.acceptCallbacks.forEach((callback) => callback(updatedModule));
+        return !state.invalidated;
+      }
+
+      // Components are re-rendered by React Refresh.
+      if (metaRegistry[moduleId]?.boundary === 'component') {
+        return !state.invalidated;
+      }
+
+      const dependents = globalModuleApi.dependents(moduleId);
+      const accepted =
+        dependents.length > 0 &&
+        dependents.every((id) => hotRegistry[id]?.depAcceptCallbacks[moduleId]);
+      if (accepted) {
+        dependents.forEach((id) => {
+          hotRegistry[id].depAcceptCallbacks[moduleId].forEach((callback) => {
+            callback(updatedModule);
+          });
+        });
+      }
+      return accepted && !state.invalidated;
+    },
+    isRefreshBoundary: (moduleId) => {
+      const refreshRuntime = (global as any).__ReactRefresh;
+      if (!refreshRuntime || !(moduleId in registry)) {
+        return false;
+      }
+
+      const exportedModule = registry[moduleId];
+      const exportNames = Object.keys(exportedModule).filter(
+        (name) => name !== '__cjs' && name !== '__esModule',
+      );
+      return (
+        exportNames.length > 0 &&
+        exportNames.every((name) => {
+          return refreshRuntime.isLikelyComponentType(exportedModule[name]);
+        })
+      );
+    },
     helpers: {
       asWildcard: (targetModule: GlobalModule) => {
         return __copyProps(obj(), targetModule, 'default');
       },
+      asModule: (value: GlobalModule) => {
+        return value?.__esModule
+          ? value
+          : __copyProps(obj({ default: value }), value, 'default');
+      },
     },
   };
 
-  __defProp(global, '__modules', { value: globalModuleApi });
+  __defProp(globalObject, namespace, { value: globalModuleApi });
 
   // Define `global` property to global object.
   if (!('global' in global)) {
@@ -0,0 +1,132 @@
+type Version = [number, number, number, string[]];
+type Comparator = (version: Version) => boolean;
+
+const VERSION_REGEX = /^v?(\d+)\.(\d+)\.(\d+)(?:-([0-9A-Za-z.-]+))?(?:\+[0-9A-Za-z.-]+)?$/;
+const PARTIAL_REGEX = /^v?(\d+|[xX*])(?:\.(\d+|[xX*]))?(?:\.(\d+|[xX*]))?(?:-([0-9A-Za-z.-]+))?$/;
+
+export const parseVersion = (version: string): Version | null => {
+  const matched = VERSION_REGEX.exec(version.trim());
+  return matched
+    ? [Number(matched[1]), Number(matched[2]), Number(matched[3]), matched[4]?.split('.') ?? []]
+    : null;
+};
+
+const comparePrerelease = (a: string[], b: string[]) => {
+  // Versions without prerelease have higher precedence.
+  if (!a.length || !b.length) {
+    return b.length - a.length;
+  }
+  for (let i = 0; i < Math.max(a.length, b.length); i++) {
+    if (a[i] === undefined) return -1;
+    if (b[i] === undefined) return 1;
+    if (a[i] === b[i]) continue;
+    const [numA, numB] = [Number(a[i]), Number(b[i])];
+    if (!isNaN(numA) && !isNaN(numB)) return numA - numB;
+    return a[i] < b[i] ? -1 : 1;
+  }
+  return 0;
+};
+
+export const compareVersions = (a: Version, b: Version) => {
+  return a[0] - b[0] || a[1] - b[1] || a[2] - b[2] || comparePrerelease(a[3], b[3]);
+};
+
+const isWildcard = (value?: string) => {
+  return value === undefined || value === 'x' || value === 'X' || value === '*';
+};
+
+/**
+ * Returns comparators of the partial version (eg. `^1.2`, `~1.2.3`, `>=1.0.0`, `1.x`).
+ */
+const parseComparator = (comparator: string): Comparator[] => {
+  const [, operator = '', rest] = /^(\^|~|>=|<=|>|<|=)?(.*)$/.exec(comparator)!;
+  const matched = PARTIAL_REGEX.exec(rest.trim());
+
+  if (!matched) {
+    return [() => false];
+  }
+
+  const [, major, minor, patch, prerelease] = matched;
+  if (isWildcard(major)) {
+    return operator === '<' || operator === '>' ? [() => false] : [() => true];
+  }
+
+  const M = Number(major);
+  const m = isWildcard(minor) ? null : Number(minor);
+  const p = isWildcard(patch) ? null : Number(patch);
+  const lower: Version = [M, m ?? 0, p ?? 0, prerelease?.split('.') ?? []];
+  const gte: Comparator = (v) => compareVersions(v, lower) >= 0;
+  const lt = (upper: Version): Comparator => (v) => compareVersions(v, upper) < 0;
+
+  switch (operator) {
+    case '^': {
+      const upper: Version =
+        M > 0 || m === null
+          ? [M + 1, 0, 0, ['0']]
+          : m > 0 || p === null
+          ? [M, m + 1, 0, ['0']]
+          : [M, m, p + 1, ['0']];
+      return [gte, lt(upper)];
+    }
+    case '~':
+      return [gte, lt(m === null ? [M + 1, 0, 0, ['0']] : [M, m + 1, 0, ['0']])];
+    case '>=':
+      return [gte];
+    case '>':
+      return [
+        m === null
+          ? (v) => compareVersions(v, [M + 1, 0, 0, ['0']]) >= 0
+          : p === null
+          ? (v) => compareVersions(v, [M, m + 1, 0, ['0']]) >= 0
+          : (v) => compareVersions(v, lower) > 0,
+      ];
+    case '<':
+      return [lt(lower)];
+    case '<=':
+      return [
+        m === null
+          ? lt([M + 1, 0, 0, ['0']])
+          : p === null
+          ? lt([M, m + 1, 0, ['0']])
+          : (v) => compareVersions(v, lower) <= 0,
+      ];
+    default:
+      // `1.2.3`, `1.2.x`, `1.x`
+      return m === null
+        ? [gte, lt([M + 1, 0, 0, ['0']])]
+        : p === null
+        ? [gte, lt([M, m + 1, 0, ['0']])]
+        : [(v) => compareVersions(v, lower) === 0];
+  }
+};
+
+/**
+ * Returns `true` if the version satisfies the semver range.
+ *
+ * Supports `||`, hyphen ranges (`1.0.0 - 2.0.0`), `^`, `~`, `>`, `>=`, `<`, `<=`, `=` and wildcards (`x`, `*`).
+ *
+ * ```ts
+ * satisfies('18.2.0', '^18.0.0'); // true
+ * satisfies('17.0.2', '>=16.8 <18 || ^19'); // true
+ * ```
+ */
+export const satisfies = (version: string, range: string): boolean => {
+  const parsed = parseVersion(version);
+  if (!parsed) {
+    return false;
+  }
+
+  return range.split('||').some((comparatorSet) => {
+    const hyphen = /^\s*(\S+)\s+-\s+(\S+)\s*$/.exec(comparatorSet);
+    const comparators = hyphen
+      ? [...parseComparator(`>=${hyphen[1]}`), ...parseComparator(`<=${hyphen[2]}`)]
+      : comparatorSet
+          .trim()
+          .replace(/(\^|~|>=|<=|>|<|=)\s+/g, '$1')
+          .split(/\s+/)
+          .filter(Boolean)
+          .flatMap(parseComparator);
+
+    return comparators.every((comparator) => comparator(parsed));
+  });
+};
@@ -2,18 +2,139 @@ export type GlobalModuleId = string;
 export type GlobalModuleRegistry = Record<GlobalModuleId, GlobalModule>;
 export type GlobalModule<T = any> = T;
 
+export interface ModuleMeta {
+  /**
+   * Resolved ids of the modules that this module depends on.
+   */
+  deps: GlobalModuleId[];
+  /**
+   * Registry keys of the external modules that this module depends on.
+   */
+  externals: string[];
+  /**
+   * HMR boundary of the module.
+   *
+   * - `component`: All exports are React components (safe to hot-swap).
+   * - `self`: The module accepts its own updates.
+   * - `undefined`: Updates must be propagated to the dependents.
+   */
+  boundary?: 'component' | 'self';
+  /**
+   * Content hash of the transformed module (`version` option of the plugin).
+   */
+  version?: string;
+}
+
+export interface ExternalVersion {
+  version: string;
+  module: GlobalModule;
+}
+
+export interface ExternalOptions {
+  /**
+   * Version of the module to register (eg. `18.2.0`).
+   */
+  version?: string;
+  /**
+   * Semver range of the module to get (eg. `^18.0.0`).
+   */
+  requiredVersion?: string;
+}
+
+export type HotData = Record<string, any>;
+export type HotAcceptCallback = (module: GlobalModule) => void;
+export type HotDisposeCallback = (data: HotData) => void;
+
+/**
+ * Hot module API of the module (`import.meta.hot`, `module.hot`).
+ */
+export interface HotContext {
+  /**
+   * Data passed from the `dispose` callbacks of the previous module instance.
+   */
+  data: HotData;
+  /**
+   * - `accept()`, `accept(callback)`: Accept updates of this module (self-accepting).
+   * - `accept(deps, callback)`: Accept updates of the dependencies.
+   *
+   * Callbacks are called with the updated module.
+   */
+  accept: (
+    deps?: GlobalModuleId | GlobalModuleId[] | HotAcceptCallback,
+    callback?: HotAcceptCallback,
+  ) => void;
+  /**
+   * Register a callback that is called before the module is replaced.
+   * Values assigned to `data` are passed to the next module instance.
+   */
+  dispose: (callback: HotDisposeCallback) => void;
+  /**
+   * Reject the current update of this module even if it is accepted.
+   */
+  invalidate: () => void;
+}
+
+export interface HotState {
+  context: HotContext;
+  selfAccepted: boolean;
+  invalidated: boolean;
+  acceptCallbacks: HotAcceptCallback[];
+  depAcceptCallbacks: Record<GlobalModuleId, HotAcceptCallback[]>;
+  disposeCallbacks: HotDisposeCallback[];
+}
+
 export interface CommonJsContext {
   exports: GlobalModule;
 }
 
+/**
+ * `exports` parameter of the module factory.
+ *
+ * - `exports(exportedModule, ...reExportedModules)`: Register as an ESM module (`esm`).
+ * - `exports.cjs()`: Returns a CommonJS module context (`cjs`).
+ */
+export interface ModuleExports {
+  (exportedModule: GlobalModule, ...reExportedModules: GlobalModule[]): void;
+  cjs: () => CommonJsContext;
+}
+
+/**
+ * `require` parameter of the module factory.
+ *
+ * - `require(id)`: Returns the module (`import`).
+ * - `require.cjs(id)`: Returns the module with CommonJS interop (`require`).
+ */
+export interface ModuleRequire {
+  (id: GlobalModuleId): GlobalModule;
+  cjs: (id: GlobalModuleId) => GlobalModule;
+}
+
+export type ModuleFactory = (
+  require: ModuleRequire,
+  exports: ModuleExports,
+  hot: HotContext,
+  // Referenced by the CommonJS module body as is (eg. `module.exports = foo`).
+  module: CommonJsContext,
+) => void;
+
+export interface ModuleDefinition {
+  meta: ModuleMeta;
+  factory: ModuleFactory;
+  evaluated: boolean;
+}
+
 export interface GlobalModuleApi {
   __registry: GlobalModuleRegistry;
   __externalRegistry: GlobalModuleRegistry;
+  __externalVersions: Record<string, ExternalVersion[]>;
+  __metaRegistry: Record<GlobalModuleId, ModuleMeta>;
+  __hotRegistry: Record<GlobalModuleId, HotState>;
+  __definitionRegistry: Record<GlobalModuleId, ModuleDefinition>;
   /**
    * Register an ESM module to global registry.
    * 
    * ```js
-   * esm('module_id', exports, ...reExports);
+   * esm('module_id', exports, { deps, externals }, ...reExports);
    * // will be registered,
    * reg = {
    *   ...exports,
@@ -24,13 +145,14 @@ export interface GlobalModuleApi {
   esm: (
     id: GlobalModuleId,
     exportedModule: GlobalModule,
+    meta: ModuleMeta,
     ...reExportedModules: GlobalModule[]
   ) => void;
   /**
    * Returns a CommonJS module context to register a CommonJS module to global registry.
    * 
    * ```js
-   * const ctx = cjs('module_id');
+   * const ctx = cjs('module_id', { deps, externals });
    *
    * // 1. Exports as default.
    * module.exports = ctx.exports = bar;
@@ -43,7 +165,25 @@ export interface GlobalModuleApi {
    * reg = { named: foo };
    * ```
    */
-  cjs: (id: GlobalModuleId) => CommonJsContext,
+  cjs: (id: GlobalModuleId, meta?: ModuleMeta) => CommonJsContext,
+  /**
+   * Define a module with the factory (`format: 'define'` option of the plugin).
+   *
+   * ```js
+   * define('module_id', { deps, externals }, (require, exports, hot, module) => {
+   *   const _react = require('react');
+   *   const { format } = require.cjs('./format');
+   *   exports({ App });
+   * });
+   * ```
+   *
+   * The factory is evaluated lazily when the module is imported (or required) for the first time.
+   * Defining an evaluated module again re-executes the factory as an update (`update`),
+   * unless the module has the same `version`.
+   *
+   * Returns `false` if the update is not accepted.
+   */
+  define: (id: GlobalModuleId, meta: ModuleMeta, factory: ModuleFactory) => boolean,
   /**
    * Register module as external to global module registry.
    * 
@@ -57,15 +197,63 @@ export interface GlobalModuleApi {
    * 
    * // get module
    * const fromGlobal = external('react');
+   *
+   * // register and get module with version
+   * external('react', __external, { version: '18.2.0' });
+   * const versioned = external('react', undefined, { requiredVersion: '^18.0.0' });
    * ```
+   *
+   * Returns the highest registered version that satisfies `requiredVersion`,
+   * or the last registered module if no versions are registered.
    */
-  external: (source: string, module?: GlobalModule) => GlobalModule,
+  external: (
+    source: string,
+    module?: GlobalModule,
+    options?: ExternalOptions,
+  ) => GlobalModule,
   import: (id: GlobalModuleId) => GlobalModule,
   require: (id: GlobalModuleId) => GlobalModule,
+  /**
+   * Returns ids of the modules that depend on the module.
+   */
+  dependents: (id: GlobalModuleId) => GlobalModuleId[],
+  /**
+   * Returns the hot module API of the module.
+   *
+   * ```js
+   * // `import.meta.hot.accept()` and `module.hot.accept()` are transformed to
+   * hot('module_id').accept();
+   * ```
+   */
+  hot: (id: GlobalModuleId) => HotContext,
+  /**
+   * Apply an update of the module.
+   *
+   * Calls `dispose` callbacks, re-evaluates the module with `evaluate` and calls `accept` callbacks
+   * of the module itself or its dependents. Updates of component boundaries (`boundary: 'component'`)
+   * are accepted without callbacks. Updates that don't change the `version` of the module
+   * are accepted without callbacks too.
+   *
+   * Returns `false` if the update is not accepted (or invalidated). Then, propagate the update to
+   * the dependents (`dependents(id)`) or reload the application.
+   */
+  update: (id: GlobalModuleId, evaluate: () => void) => boolean,
+  /**
+   * Returns `true` if all exports of the module are React components (React Fast Refresh boundary).
+   *
+   * Requires the React Refresh runtime at `global.__ReactRefresh` (`react-refresh/runtime`).
+   */
+  isRefreshBoundary: (id: GlobalModuleId) => boolean,
   helpers: {
     /**
      * Helper for `import *` (exclude `default` property).
      */
     asWildcard: (targetModule: GlobalModule) => GlobalModule;
+    /**
+     * Helper for externals provided by global variables or `require` (non-ES modules).
+     *
+     * Returns ES modules as is, others with the value as `default` property.
+     */
+    asModule: (value: GlobalModule) => GlobalModule;
   },
 };
@@ -1,41 +1,46 @@
-use std::collections::HashMap;
-
-use serde::Deserialize;
 use swc_core::{
-    ecma::{ast::Program, visit::FoldWith},
+    common::errors::HANDLER,
+    ecma::{
+        ast::Program,
+        visit::{Fold, FoldWith},
+    },
     plugin::{
         metadata::TransformPluginMetadataContextKind, plugin_transform,
         proxies::TransformPluginProgramMetadata,
     },
 };
-use swc_global_module::global_module;
+use swc_global_module::{global_module, Config, ConfigError};
+
+fn create_transform(metadata: &TransformPluginProgramMetadata) -> Result<impl Fold, ConfigError> {
+    let mut config = Config::from_json(
+        &metadata
+            .get_transform_plugin_config()
+            .unwrap_or(String::from("{}")),
+    )?;
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GlobalModuleOptions {
-    module_id: Option<String>,
-    runtime_module: Option<bool>,
-    external_pattern: Option<String>,
-    module_ids: Option<HashMap<String, String>>,
+    let filename = metadata
+        .get_context(&TransformPluginMetadataContextKind::Filename)
+        .ok_or_else(|| ConfigError::new("filename", "filename of the module is required"))?;
+
+    if config.root.is_none() {
+        config.root = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+    }
+
+    global_module(config, filename, metadata.comments)
 }
 
 #[plugin_transform]
 pub fn global_module_plugin(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let config = serde_json::from_str::<GlobalModuleOptions>(
-        &metadata
-            .get_transform_plugin_config()
-            .expect("failed to get plugin config for swc-plugin-global-module"),
-    )
-    .expect("invalid config for swc-plugin-global-module");
-
-    program.fold_with(&mut global_module(
-        config.module_id.unwrap_or(
-            metadata
-                .get_context(&TransformPluginMetadataContextKind::Filename)
-                .unwrap(),
-        ),
-        config.runtime_module.unwrap_or(false),
-        config.external_pattern,
-        config.module_ids,
-    ))
+    match create_transform(&metadata) {
+        Ok(mut transform) => program.fold_with(&mut transform),
+        Err(error) => {
+            // Report the invalid option and return the program untouched.
+            HANDLER.with(|handler| {
+                handler
+                    .struct_err(&format!("swc-plugin-global-module: {error}"))
+                    .emit()
+            });
+            program
+        }
+    }
 }
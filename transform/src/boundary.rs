@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+use swc_core::ecma::ast::*;
+
+use crate::{
+    esm_collector::{ExportModule, ModuleType},
+    helpers::is_component_name,
+    react_refresh::is_hoc_call,
+};
+
+/// HMR boundary of the module.
+///
+/// Registered with the module metadata so that the runtime can decide between
+/// re-executing the module and propagating the update to its dependents.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Boundary {
+    /// All exports are React components (safe to hot-swap).
+    Component,
+    /// The module accepts its own updates (`import.meta.hot.accept()`).
+    SelfAccepting,
+    /// Updates must be propagated to the dependents.
+    #[default]
+    Propagate,
+}
+
+impl Boundary {
+    /// Classifies the module with the collected exports and the converted module items.
+    pub fn classify(items: &[ModuleItem], exports: &[ExportModule], self_accepting: bool) -> Self {
+        if self_accepting {
+            return Boundary::SelfAccepting;
+        }
+
+        let components = collect_components(items, exports);
+        if !exports.is_empty()
+            && exports
+                .iter()
+                .all(|export| components.contains(&export.ident.to_id()))
+        {
+            Boundary::Component
+        } else {
+            Boundary::Propagate
+        }
+    }
+
+    /// Returns the value of the `boundary` metadata (`None` if the update must be propagated).
+    pub fn as_meta_value(&self) -> Option<&'static str> {
+        match self {
+            Boundary::Component => Some("component"),
+            Boundary::SelfAccepting => Some("self"),
+            Boundary::Propagate => None,
+        }
+    }
+}
+
+/// Returns ids of the top-level component declarations that React Refresh registers.
+///
+/// - Functions with capitalized names (eg. `function App() {}`, `const App = () => <div />`)
+/// - Higher-order components with capitalized names (eg. `const App = memo(() => <div />)`)
+/// - Default exports of higher-order components (eg. `export default memo(App)`)
+/// - Aliases of the components (eg. `const __export_default = App`)
+///
+/// Classes, other call results (eg. `const CONFIG = createConfig()`) and anonymous functions
+/// are not registered, so they are not components.
+pub fn collect_components(items: &[ModuleItem], exports: &[ExportModule]) -> HashSet<Id> {
+    let mut collector = ComponentCollector {
+        default_export: exports
+            .iter()
+            .find(|export| matches!(export.module_type, ModuleType::Default))
+            .map(|export| export.ident.to_id()),
+        ..Default::default()
+    };
+
+    for item in items {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                collector.add_decl(decl)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident), ..
+                    }),
+                ..
+            })) => collector.add_fn(ident),
+            // `export default __export_default = expr` (non-runtime mode)
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) => {
+                if let Expr::Assign(AssignExpr { left, right, .. }) = &**expr {
+                    if let Some(ident) = left.as_ident() {
+                        collector.add_var(ident, right);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    collector.into_components()
+}
+
+#[derive(Default)]
+struct ComponentCollector {
+    default_export: Option<Id>,
+    components: HashSet<Id>,
+    aliases: Vec<(Id, Id)>,
+}
+
+impl ComponentCollector {
+    fn into_components(mut self) -> HashSet<Id> {
+        self.aliases.into_iter().for_each(|(id, alias)| {
+            if self.components.contains(&alias) {
+                self.components.insert(id);
+            }
+        });
+        self.components
+    }
+
+    fn add_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Fn(FnDecl { ident, .. }) => self.add_fn(ident),
+            Decl::Var(var_decl) => var_decl.decls.iter().for_each(|var_declarator| {
+                if let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+                    (&var_declarator.name, &var_declarator.init)
+                {
+                    self.add_var(id, init);
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    fn add_fn(&mut self, ident: &Ident) {
+        if is_component_name(&ident.sym) {
+            self.components.insert(ident.to_id());
+        }
+    }
+
+    fn add_var(&mut self, ident: &Ident, init: &Expr) {
+        match init {
+            Expr::Fn(_) | Expr::Arrow(_) => self.add_fn(ident),
+            // Registered as `%default%` if it's the default export.
+            Expr::Call(call_expr)
+                if is_hoc_call(call_expr)
+                    && (is_component_name(&ident.sym)
+                        || self.default_export == Some(ident.to_id())) =>
+            {
+                self.components.insert(ident.to_id());
+            }
+            // `_c = memo(App)` (default exports instrumented by React Refresh)
+            Expr::Assign(AssignExpr { right, .. }) => self.add_var(ident, right),
+            Expr::Ident(alias) => self.aliases.push((ident.to_id(), alias.to_id())),
+            _ => {}
+        }
+    }
+}
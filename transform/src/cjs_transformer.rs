@@ -8,51 +8,64 @@ use swc_core::{
 };
 
 use crate::{
-    constants::{CJS_API_NAME, GLOBAL, MODULE},
-    helpers::{decl_var_and_assign_stmt, obj_member_expr, require_module_from_global},
-    module_resolver::ModuleResolver,
+    constants::CJS_API_NAME,
+    dependencies::Dependencies,
+    externals::External,
+    helpers::{
+        decl_var_and_assign_stmt, external_module_from_global, global_var_expr, obj_lit,
+        obj_member_expr, require_module_from_global,
+    },
+    registry::ModuleRegistry,
+    resolver::{report_unresolved, Resolver},
 };
 
-#[derive(Debug)]
-pub struct Require {
-    pub ident: Ident,
-    pub module_src: String,
-}
-
-#[derive(Debug)]
-pub struct Exports {
-    pub expr: Expr,
-    pub name: String,
-}
-
 pub struct CommonJsTransformer<'a> {
-    resolver: &'a ModuleResolver,
+    resolver: &'a dyn Resolver,
     module_id: String,
     runtime_module: bool,
+    strict: bool,
+    registry: &'a ModuleRegistry,
+    dependencies: Dependencies,
     cjs_boundary_ident: Ident,
     exported: i32,
 }
 
 impl<'a> CommonJsTransformer<'a> {
-    pub fn new(resolver: &'a ModuleResolver, module_id: String, runtime_module: bool) -> Self {
+    pub fn new(
+        resolver: &'a dyn Resolver,
+        module_id: String,
+        runtime_module: bool,
+        strict: bool,
+        registry: &'a ModuleRegistry,
+        dependencies: Dependencies,
+    ) -> Self {
         CommonJsTransformer {
             resolver,
             module_id,
             runtime_module,
+            strict,
+            registry,
+            dependencies,
             cjs_boundary_ident: private_ident!("__cjs"),
             exported: 0,
         }
     }
 
+    /// Returns the metadata of the module with the collected dependencies.
+    ///
+    /// eg. `{ deps, externals }`
+    pub fn meta(&self) -> Expr {
+        obj_lit(Some(self.dependencies.as_meta_props()))
+    }
+
     /// Returns an expression that create new CommonJS boundary.
     ///
-    /// eg. `const boundary = global.__modules.cjs("module_id")`
+    /// eg. `const boundary = global.__modules.cjs("module_id", { deps, externals })`
     fn get_cjs_boundary(&mut self) -> Expr {
-        obj_member_expr(
-            obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-            quote_ident!(CJS_API_NAME).into(),
+        self.registry.api(CJS_API_NAME).as_call(
+            DUMMY_SP,
+            vec![self.module_id.as_str().as_arg(), self.meta().as_arg()],
         )
-        .as_call(DUMMY_SP, vec![self.module_id.as_str().as_arg()])
     }
 }
 
@@ -72,19 +85,16 @@ impl VisitMut for CommonJsTransformer<'_> {
 
     fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
         stmt.visit_mut_children_with(self);
-        match stmt {
-            Stmt::Expr(ExprStmt { expr, .. }) => {
-                if expr.is_invalid() {
-                    stmt.take();
-                }
+        if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
+            if expr.is_invalid() {
+                stmt.take();
             }
-            _ => {}
         }
     }
 
     fn visit_mut_exprs(&mut self, exprs: &mut Vec<Box<Expr>>) {
         exprs.visit_mut_children_with(self);
-        exprs.retain(|expr| if expr.is_invalid() { false } else { true });
+        exprs.retain(|expr| !expr.is_invalid());
     }
 
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
@@ -97,26 +107,50 @@ impl VisitMut for CommonJsTransformer<'_> {
                 callee: Callee::Expr(callee_expr),
                 type_args: None,
                 ..
-            }) if self.runtime_module
-                && args.len() == 1
-                && callee_expr.is_ident_ref_to("require") =>
-            {
-                let src = match args.first().unwrap() {
+            }) if args.len() == 1 && callee_expr.is_ident_ref_to("require") => {
+                let (src, span) = match args.first().unwrap() {
                     ExprOrSpread {
                         spread: None,
                         expr: arg_expr,
                     } => match **arg_expr {
-                        Expr::Lit(Lit::Str(ref src)) => src.value.to_string(),
+                        Expr::Lit(Lit::Str(ref src)) => (src.value.to_string(), src.span),
                         _ => return,
                     },
                     _ => return,
                 };
-                *expr = require_module_from_global(
-                    &self
-                        .resolver
-                        .to_actual_path(&src, false)
-                        .unwrap_or(src.to_string()),
-                );
+
+                self.dependencies.add(self.resolver, &src);
+
+                if self.strict {
+                    report_unresolved(self.resolver, &src, span);
+                }
+
+                if !self.runtime_module {
+                    return;
+                }
+
+                match self.resolver.external(&src) {
+                    Some(External::Registry {
+                        key,
+                        required_version,
+                        ..
+                    }) => {
+                        *expr = external_module_from_global(
+                            self.registry,
+                            &key,
+                            required_version.as_deref(),
+                        )
+                    }
+                    Some(External::Global(path)) => *expr = global_var_expr(&path),
+                    // Keep `require` as is.
+                    Some(External::Require) => {}
+                    None => {
+                        *expr = require_module_from_global(
+                            self.registry,
+                            &self.resolver.resolve(&src).unwrap_or(src.to_string()),
+                        );
+                    }
+                }
             }
             // Exports
             // `module.exports = foo`
@@ -126,12 +160,13 @@ impl VisitMut for CommonJsTransformer<'_> {
                 left,
                 right,
                 ..
-            }) => match left {
-                AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+            }) => {
+                if let AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
                     obj,
                     prop: MemberProp::Ident(prop_ident),
                     ..
-                })) => {
+                })) = left
+                {
                     let export_name = if obj.is_ident_ref_to("exports") {
                         prop_ident.sym.as_str()
                     } else if obj.is_ident_ref_to("module") && prop_ident.sym == "exports" {
@@ -161,8 +196,7 @@ impl VisitMut for CommonJsTransformer<'_> {
                         )
                         .make_assign_to(AssignOp::Assign, left.clone());
                 }
-                _ => {}
-            },
+            }
             _ => expr.visit_mut_children_with(self),
         }
     }
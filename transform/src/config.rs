@@ -0,0 +1,489 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    constants::{GLOBAL, MODULE},
+    error::ConfigError,
+    externals::ExternalRule,
+    format::ModuleFormat,
+    glob::Glob,
+    module_id::{relative_path, ModuleIdGenerator, ModuleIdStrategy},
+    module_resolver::ModuleIds,
+    path_resolver::{normalize_path, ResolveOptions},
+};
+
+/// Options of the global module transform.
+///
+/// ```
+/// use swc_global_module::{Config, ExternalRule};
+///
+/// let config = Config::default()
+///     .with_runtime_module(true)
+///     .with_external(ExternalRule::pattern("^react"));
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct Config {
+    /// Module id.
+    ///
+    /// Defaults to the id generated from the filename with `moduleIdStrategy`.
+    pub module_id: Option<String>,
+    /// Convert import statements to custom module system and remove export statements.
+    ///
+    /// Defaults to `false`.
+    pub runtime_module: bool,
+    /// External import source pattern to register to external registry.
+    ///
+    /// Matched after `externals`.
+    pub external_pattern: Option<String>,
+    /// External rules matched in order (first match wins).
+    pub externals: Vec<ExternalRule>,
+    /// Import source pattern of the imports and re-exports that are kept as native statements
+    /// in runtime mode (eg. polyfills, `react/jsx-runtime` or virtual modules of the bundler).
+    ///
+    /// Requires the `register` format.
+    pub passthrough_pattern: Option<String>,
+    /// Actual module ids of the import sources.
+    pub module_ids: Option<ModuleIds>,
+    /// Resolve import sources into file paths.
+    pub resolve: Option<ResolveOptions>,
+    /// Strategy to generate module ids from file paths.
+    ///
+    /// Defaults to `"filename"`.
+    pub module_id_strategy: Option<ModuleIdStrategy>,
+    /// Base directory of relative paths.
+    ///
+    /// Defaults to the current working directory (plugin) or empty.
+    pub root: Option<String>,
+    /// Report import sources that are neither resolved nor external as errors.
+    ///
+    /// Defaults to `false`.
+    pub strict: bool,
+    /// Output format of the module (formats other than `"register"` require `runtimeModule`).
+    ///
+    /// Defaults to `"register"`.
+    pub format: ModuleFormat,
+    /// Register components for React Fast Refresh (`$RefreshReg$`, `$RefreshSig$`) with the module id
+    /// and check the refresh boundary of the module.
+    ///
+    /// Defaults to `false`.
+    pub react_refresh: bool,
+    /// Register the content hash of the transformed module as `version` in the metadata
+    /// so that the runtime can skip updates that don't change the module.
+    ///
+    /// Defaults to `false`.
+    pub version: bool,
+    /// Root expression of the global module registry (eg. `globalThis`, `self`, `window`).
+    ///
+    /// Defaults to `"global"`.
+    pub global_object: String,
+    /// Property name of the global module registry.
+    ///
+    /// Defaults to `"__modules"`.
+    pub namespace: String,
+    /// Globs of the files to transform.
+    ///
+    /// Globs are matched against the filename and the path relative to the `root`.
+    ///
+    /// Defaults to all files.
+    pub include: Vec<String>,
+    /// Globs of the files to skip (takes precedence over `include`).
+    pub exclude: Vec<String>,
+    /// Options for the files matched by globs (applied in order).
+    pub overrides: Vec<ConfigOverride>,
+}
+
+/// Options that override the config for the matched files.
+///
+/// eg. `{ "files": ["**/*.test.ts"], "runtimeModule": false }`
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConfigOverride {
+    /// Globs of the files to override.
+    pub files: Vec<String>,
+    /// Overrides `runtimeModule`.
+    ///
+    /// Files without the runtime module fall back to the `"register"` format.
+    #[serde(default)]
+    pub runtime_module: Option<bool>,
+    /// Replaces `externals` (`externalPattern` is not applied to the files).
+    #[serde(default)]
+    pub externals: Option<Vec<ExternalRule>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            module_id: None,
+            runtime_module: false,
+            external_pattern: None,
+            externals: Vec::new(),
+            passthrough_pattern: None,
+            module_ids: None,
+            resolve: None,
+            module_id_strategy: None,
+            root: None,
+            strict: false,
+            format: ModuleFormat::Register,
+            react_refresh: false,
+            version: false,
+            global_object: GLOBAL.to_string(),
+            namespace: MODULE.to_string(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Parses the JSON options (eg. the plugin config).
+    ///
+    /// Returns an error that names the offending option (unknown options and wrong types).
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        let options = match serde_json::from_str(json) {
+            Ok(Value::Object(options)) => options,
+            Ok(_) => return Err(ConfigError::new("config", "expected an object")),
+            Err(error) => return Err(ConfigError::new("config", error)),
+        };
+
+        // Deserialize options one by one to report the name of the offending option.
+        for (name, value) in &options {
+            serde_json::from_value::<Config>(Value::Object(Map::from_iter([(
+                name.clone(),
+                value.clone(),
+            )])))
+            .map_err(|error| ConfigError::new(name.as_str(), error))?;
+        }
+
+        serde_json::from_value(Value::Object(options))
+            .map_err(|error| ConfigError::new("config", error))
+    }
+
+    /// Returns the JSON schema of the options (for editor validation).
+    #[cfg(feature = "schema")]
+    pub fn json_schema() -> Value {
+        serde_json::to_value(schemars::schema_for!(Config)).unwrap_or_default()
+    }
+
+    pub fn with_module_id(mut self, module_id: impl Into<String>) -> Self {
+        self.module_id = Some(module_id.into());
+        self
+    }
+
+    pub fn with_runtime_module(mut self, runtime_module: bool) -> Self {
+        self.runtime_module = runtime_module;
+        self
+    }
+
+    pub fn with_external_pattern(mut self, external_pattern: impl Into<String>) -> Self {
+        self.external_pattern = Some(external_pattern.into());
+        self
+    }
+
+    /// Appends the external rule.
+    pub fn with_external(mut self, external: ExternalRule) -> Self {
+        self.externals.push(external);
+        self
+    }
+
+    pub fn with_externals(mut self, externals: Vec<ExternalRule>) -> Self {
+        self.externals = externals;
+        self
+    }
+
+    pub fn with_passthrough_pattern(mut self, passthrough_pattern: impl Into<String>) -> Self {
+        self.passthrough_pattern = Some(passthrough_pattern.into());
+        self
+    }
+
+    pub fn with_module_ids(mut self, module_ids: impl Into<ModuleIds>) -> Self {
+        self.module_ids = Some(module_ids.into());
+        self
+    }
+
+    pub fn with_resolve(mut self, resolve: ResolveOptions) -> Self {
+        self.resolve = Some(resolve);
+        self
+    }
+
+    pub fn with_module_id_strategy(mut self, module_id_strategy: ModuleIdStrategy) -> Self {
+        self.module_id_strategy = Some(module_id_strategy);
+        self
+    }
+
+    pub fn with_root(mut self, root: impl Into<String>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_format(mut self, format: ModuleFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_react_refresh(mut self, react_refresh: bool) -> Self {
+        self.react_refresh = react_refresh;
+        self
+    }
+
+    pub fn with_version(mut self, version: bool) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn with_global_object(mut self, global_object: impl Into<String>) -> Self {
+        self.global_object = global_object.into();
+        self
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn with_include(mut self, include: Vec<String>) -> Self {
+        self.include = include;
+        self
+    }
+
+    pub fn with_exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = exclude;
+        self
+    }
+
+    /// Appends the override.
+    pub fn with_override(mut self, config_override: ConfigOverride) -> Self {
+        self.overrides.push(config_override);
+        self
+    }
+
+    /// Returns `true` if the file is matched by `include` and not by `exclude`.
+    pub fn is_included(&self, filename: &str) -> Result<bool, ConfigError> {
+        Ok(
+            (self.include.is_empty() || self.matches("include", &self.include, filename)?)
+                && !self.matches("exclude", &self.exclude, filename)?,
+        )
+    }
+
+    /// Returns the config with the `overrides` matched by the file.
+    ///
+    /// The `format` falls back to `"register"` if `runtimeModule` is disabled by the overrides
+    /// (other formats require `runtimeModule`).
+    pub fn for_file(&self, filename: &str) -> Result<Config, ConfigError> {
+        let mut config = self.clone();
+
+        for (index, config_override) in self.overrides.iter().enumerate() {
+            if !self.matches(
+                &format!("overrides[{index}].files"),
+                &config_override.files,
+                filename,
+            )? {
+                continue;
+            }
+            if let Some(runtime_module) = config_override.runtime_module {
+                config.runtime_module = runtime_module;
+                if !runtime_module {
+                    config.format = ModuleFormat::Register;
+                }
+            }
+            if let Some(externals) = &config_override.externals {
+                config.externals = externals.clone();
+                config.external_pattern = None;
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Returns `true` if the file is matched by any of the globs.
+    fn matches(&self, option: &str, globs: &[String], filename: &str) -> Result<bool, ConfigError> {
+        let globs = globs
+            .iter()
+            .enumerate()
+            .map(|(index, glob)| {
+                Glob::new(glob)
+                    .map_err(|error| ConfigError::new(format!("{option}[{index}]"), error))
+            })
+            .collect::<Result<Vec<Glob>, ConfigError>>()?;
+        let path = normalize_path(filename);
+        let relative_path = self
+            .root
+            .as_deref()
+            .map(|root| relative_path(&normalize_path(root), &path));
+
+        Ok(globs.iter().any(|glob| {
+            glob.is_match(&path)
+                || relative_path
+                    .as_deref()
+                    .is_some_and(|path| glob.is_match(path))
+        }))
+    }
+
+    /// Returns the module id generator of the `moduleIdStrategy` option.
+    ///
+    /// The generator is created per call, so collisions are detected within the file only.
+    pub(crate) fn module_id_generator(&self) -> Option<ModuleIdGenerator> {
+        self.module_id_strategy.clone().map(|strategy| {
+            ModuleIdGenerator::new(strategy, self.root.as_deref().unwrap_or_default())
+        })
+    }
+
+    /// Returns the `moduleId` option or the id generated from the filename.
+    pub(crate) fn module_id(&self, filename: &str) -> String {
+        self.module_id.clone().unwrap_or_else(|| {
+            self.module_id_generator()
+                .map(|module_id_generator| module_id_generator.generate(filename))
+                .unwrap_or(filename.to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        let config = Config::from_json("{}").unwrap();
+
+        assert!(!config.runtime_module);
+        assert_eq!(config.global_object, "global");
+        assert_eq!(config.namespace, "__modules");
+        assert_eq!(config.module_id("src/App.tsx"), "src/App.tsx");
+    }
+
+    #[test]
+    fn from_json() {
+        let config = Config::from_json(
+            r#"{
+                "runtimeModule": true,
+                "externals": [{ "source": "react", "global": "React" }],
+                "moduleIdStrategy": "relative",
+                "root": "/app"
+            }"#,
+        )
+        .unwrap();
+
+        assert!(config.runtime_module);
+        assert_eq!(config.externals.len(), 1);
+        assert_eq!(config.module_id("/app/src/App.tsx"), "src/App.tsx");
+    }
+
+    #[test]
+    fn invalid_options() {
+        let error = |json| Config::from_json(json).unwrap_err().option().to_string();
+
+        assert_eq!(error(r#"{ "runtimeModul": true }"#), "runtimeModul");
+        assert_eq!(error(r#"{ "strict": "yes" }"#), "strict");
+        assert_eq!(
+            error(r#"{ "externals": [{ "sorce": "react" }] }"#),
+            "externals"
+        );
+        assert_eq!(error("[]"), "config");
+    }
+
+    #[test]
+    fn builder() {
+        let config = Config::default()
+            .with_module_id("app")
+            .with_runtime_module(true)
+            .with_external(ExternalRule::pattern("^react"))
+            .with_namespace("__app_modules");
+
+        assert_eq!(config.module_id("src/App.tsx"), "app");
+        assert!(config.runtime_module);
+        assert_eq!(config.externals.len(), 1);
+        assert_eq!(config.namespace, "__app_modules");
+    }
+
+    #[test]
+    fn include_and_exclude() {
+        let config = Config::default()
+            .with_root("/app")
+            .with_include(vec!["src/**".into()])
+            .with_exclude(vec!["**/*.generated.ts".into()]);
+
+        assert!(config.is_included("/app/src/App.tsx").unwrap());
+        assert!(!config.is_included("/app/src/api.generated.ts").unwrap());
+        assert!(!config.is_included("/app/vendor/lib.js").unwrap());
+        assert!(Config::default().is_included("/app/vendor/lib.js").unwrap());
+    }
+
+    #[test]
+    fn overrides() {
+        let config = Config::default()
+            .with_runtime_module(true)
+            .with_external_pattern("^react$")
+            .with_override(ConfigOverride {
+                files: vec!["**/*.test.ts".into()],
+                runtime_module: Some(false),
+                ..Default::default()
+            })
+            .with_override(ConfigOverride {
+                files: vec!["**/vendor/**".into()],
+                externals: Some(vec![ExternalRule::pattern(".*")]),
+                ..Default::default()
+            });
+
+        let test_config = config.for_file("src/App.test.ts").unwrap();
+        assert!(!test_config.runtime_module);
+        assert!(test_config.externals.is_empty());
+        assert_eq!(test_config.external_pattern.as_deref(), Some("^react$"));
+
+        let vendor_config = config.for_file("vendor/lib.js").unwrap();
+        assert!(vendor_config.runtime_module);
+        assert_eq!(vendor_config.externals.len(), 1);
+        assert_eq!(vendor_config.external_pattern, None);
+    }
+
+    #[test]
+    fn overrides_without_runtime_module() {
+        let config = Config::default()
+            .with_runtime_module(true)
+            .with_format(ModuleFormat::Define)
+            .with_override(ConfigOverride {
+                files: vec!["**/*.test.ts".into()],
+                runtime_module: Some(false),
+                ..Default::default()
+            });
+
+        let test_config = config.for_file("src/App.test.ts").unwrap();
+        assert!(!test_config.runtime_module);
+        assert_eq!(test_config.format, ModuleFormat::Register);
+        assert_eq!(
+            config.for_file("src/App.ts").unwrap().format,
+            ModuleFormat::Define
+        );
+    }
+
+    #[test]
+    fn invalid_globs() {
+        let config = Config::default().with_override(ConfigOverride {
+            files: vec!["src/**".into(), "{a,b".into()],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            config.for_file("src/App.ts").unwrap_err().option(),
+            "overrides[0].files[1]"
+        );
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn json_schema() {
+        let schema = Config::json_schema();
+
+        assert!(schema["properties"]["runtimeModule"].is_object());
+        assert!(schema["properties"]["externals"].is_object());
+        assert_eq!(schema["additionalProperties"], Value::Bool(false));
+    }
+}
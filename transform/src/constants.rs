@@ -5,6 +5,25 @@ pub const MODULE_REQUIRE_NAME: &str = "require";
 pub const MODULE_EXTERNAL_NAME: &str = "external";
 pub const MODULE_HELPER_NAME: &str = "helpers";
 pub const HELPER_AS_WILDCARD_NAME: &str = "asWildcard";
+pub const HELPER_AS_MODULE_NAME: &str = "asModule";
 
 pub const ESM_API_NAME: &str = "esm";
 pub const CJS_API_NAME: &str = "cjs";
+pub const DEFINE_API_NAME: &str = "define";
+pub const CJS_MARKER_NAME: &str = "__cjs";
+pub const HOT_API_NAME: &str = "hot";
+pub const HOT_ACCEPT_NAME: &str = "accept";
+pub const HOT_INVALIDATE_NAME: &str = "invalidate";
+pub const HOT_DISPOSE_NAME: &str = "dispose";
+pub const HOT_DATA_NAME: &str = "data";
+pub const IS_REFRESH_BOUNDARY_API_NAME: &str = "isRefreshBoundary";
+
+pub const REFRESH_REG_NAME: &str = "$RefreshReg$";
+pub const REFRESH_SIG_NAME: &str = "$RefreshSig$";
+pub const REFRESH_DEFAULT_NAME: &str = "%default%";
+pub const REFRESH_DATA_NAME: &str = "__refreshed";
+
+pub const SYSTEM_NAME: &str = "System";
+pub const SYSTEM_REGISTER_NAME: &str = "register";
+
+pub const METRO_DEFINE_NAME: &str = "__d";
@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+use swc_core::ecma::{
+    ast::*,
+    utils::quote_ident,
+    visit::{noop_visit_type, Visit, VisitWith},
+};
+
+use crate::{boundary::Boundary, externals::External, helpers::str_array_lit, resolver::Resolver};
+
+/// Resolved dependencies of the module.
+///
+/// Registered to the global module registry with the module's exports
+/// so that the runtime can build an inverse dependency graph (eg. HMR propagation).
+#[derive(Clone, Debug, Default)]
+pub struct Dependencies {
+    /// Resolved module ids.
+    pub deps: BTreeSet<String>,
+    /// Registry keys of external modules.
+    pub externals: BTreeSet<String>,
+    /// HMR boundary of the module.
+    pub boundary: Boundary,
+}
+
+impl Dependencies {
+    /// Resolve the import source and add it to dependencies.
+    ///
+    /// Externals are added by the key of the external registry that the runtime looks up.
+    /// Externals provided by global variables or `require` are not tracked.
+    pub fn add(&mut self, resolver: &dyn Resolver, src: &str) {
+        match resolver.external(src) {
+            Some(External::Registry { key, .. }) => {
+                self.externals.insert(key);
+            }
+            Some(_) => {}
+            None => {
+                self.deps
+                    .insert(resolver.resolve(src).unwrap_or(src.to_string()));
+            }
+        }
+    }
+
+    /// Returns properties of the module metadata object.
+    ///
+    /// eg. `deps: ["id_1", "id_2"], externals: ["react"], boundary: "component"`
+    pub fn as_meta_props(&self) -> Vec<PropOrSpread> {
+        let mut props = vec![
+            Prop::KeyValue(KeyValueProp {
+                key: quote_ident!("deps").into(),
+                value: str_array_lit(self.deps.iter()).into(),
+            })
+            .into(),
+            Prop::KeyValue(KeyValueProp {
+                key: quote_ident!("externals").into(),
+                value: str_array_lit(self.externals.iter()).into(),
+            })
+            .into(),
+        ];
+        if let Some(boundary) = self.boundary.as_meta_value() {
+            props.push(
+                Prop::KeyValue(KeyValueProp {
+                    key: quote_ident!("boundary").into(),
+                    value: Expr::from(boundary).into(),
+                })
+                .into(),
+            );
+        }
+        props
+    }
+}
+
+/// Returns the source literals of the dynamic imports (eg. `import('./Page')`).
+pub fn collect_dynamic_imports(module: &Module) -> Vec<Str> {
+    let mut collector = DynamicImportCollector::default();
+    module.visit_with(&mut collector);
+    collector.sources
+}
+
+#[derive(Default)]
+struct DynamicImportCollector {
+    sources: Vec<Str>,
+}
+
+impl Visit for DynamicImportCollector {
+    noop_visit_type!();
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        if let (Callee::Import(_), Some(Expr::Lit(Lit::Str(src)))) = (
+            &call_expr.callee,
+            call_expr.args.first().map(|arg| &*arg.expr),
+        ) {
+            self.sources.push(src.clone());
+        }
+        call_expr.visit_children_with(self);
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+/// Invalid option of the transform.
+///
+/// eg. `` invalid option `moduleIds[1].regex`: regex parse error ... ``
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigError {
+    option: String,
+    message: String,
+}
+
+impl ConfigError {
+    pub fn new(option: impl Into<String>, message: impl fmt::Display) -> Self {
+        ConfigError {
+            option: option.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Path of the offending option (eg. `externals[0].pattern`).
+    pub fn option(&self) -> &str {
+        &self.option
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid option `{}`: {}", self.option, self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
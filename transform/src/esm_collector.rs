@@ -1,6 +1,6 @@
 use crate::helpers::is_invalid_module_decl;
 use swc_core::{
-    common::{util::take::Take, DUMMY_SP},
+    common::{util::take::Take, Span, DUMMY_SP},
     ecma::{
         ast::*,
         utils::{private_ident, ExprFactory},
@@ -24,41 +24,41 @@ pub struct ImportModule {
     pub ident: Ident,
     pub imported: Option<Ident>,
     pub module_src: String,
+    // Span of the source literal (for diagnostics).
+    pub module_src_span: Span,
     pub module_type: ModuleType,
     pub as_export: bool,
 }
 
 impl ImportModule {
-    fn default(ident: Ident, imported: Option<Ident>, module_src: String, as_export: bool) -> Self {
+    fn default(ident: Ident, imported: Option<Ident>, module_src: &Str, as_export: bool) -> Self {
         ImportModule {
             ident,
             imported,
-            module_src,
+            module_src: module_src.value.to_string(),
+            module_src_span: module_src.span,
             module_type: ModuleType::Default,
             as_export,
         }
     }
 
-    fn named(ident: Ident, imported: Option<Ident>, module_src: String, as_export: bool) -> Self {
+    fn named(ident: Ident, imported: Option<Ident>, module_src: &Str, as_export: bool) -> Self {
         ImportModule {
             ident,
             imported,
-            module_src,
+            module_src: module_src.value.to_string(),
+            module_src_span: module_src.span,
             module_type: ModuleType::Named,
             as_export,
         }
     }
 
-    fn namespace(
-        ident: Ident,
-        imported: Option<Ident>,
-        module_src: String,
-        as_export: bool,
-    ) -> Self {
+    fn namespace(ident: Ident, imported: Option<Ident>, module_src: &Str, as_export: bool) -> Self {
         ImportModule {
             ident,
             imported,
-            module_src,
+            module_src: module_src.value.to_string(),
+            module_src_span: module_src.span,
             module_type: ModuleType::NamespaceOrAll,
             as_export,
         }
@@ -105,6 +105,8 @@ pub struct EsModuleCollector {
     decls: Vec<Ident>,
     pub imports: Vec<ImportModule>,
     pub exports: Vec<ExportModule>,
+    /// Sources of the imports without specifiers (eg. `import './styles'`).
+    pub side_effect_imports: Vec<Str>,
 }
 
 impl EsModuleCollector {
@@ -114,6 +116,7 @@ impl EsModuleCollector {
             decls: Default::default(),
             imports: Default::default(),
             exports: Default::default(),
+            side_effect_imports: Default::default(),
         }
     }
 
@@ -261,7 +264,7 @@ impl EsModuleCollector {
             Decl::Var(var_decl) => {
                 if let Some(var_ident) = var_decl
                     .decls
-                    .get(0)
+                    .first()
                     .and_then(|var_declarator| var_declarator.name.as_ident())
                 {
                     debug!("export decl var: {:#?}", var_ident.id.sym);
@@ -309,8 +312,8 @@ impl VisitMut for EsModuleCollector {
 
     fn visit_mut_module_items(&mut self, stmts: &mut Vec<ModuleItem>) {
         for stmt in stmts.iter_mut() {
-            match stmt {
-                ModuleItem::ModuleDecl(module_decl) => match module_decl {
+            if let ModuleItem::ModuleDecl(module_decl) = stmt {
+                match module_decl {
                     ModuleDecl::Import(_) => {
                         module_decl.visit_mut_children_with(self);
                     }
@@ -336,8 +339,7 @@ impl VisitMut for EsModuleCollector {
                         }
                     }
                     _ => module_decl.visit_mut_children_with(self),
-                },
-                _ => {}
+                }
             }
 
             if self.runtime_module && stmt.is_module_decl() {
@@ -345,7 +347,7 @@ impl VisitMut for EsModuleCollector {
             }
         }
 
-        if self.decls.len() > 0 {
+        if !self.decls.is_empty() {
             stmts.extend(self.decls.iter().map(|ident| {
                 Stmt::Decl(Decl::Var(Box::new(VarDecl {
                     declare: false,
@@ -365,10 +367,8 @@ impl VisitMut for EsModuleCollector {
         stmts.retain(|stmt| {
             if let Some(module_decl) = stmt.as_module_decl() {
                 !is_invalid_module_decl(module_decl)
-            } else if matches!(stmt, ModuleItem::Stmt(Stmt::Empty(..))) {
-                false
             } else {
-                true
+                !matches!(stmt, ModuleItem::Stmt(Stmt::Empty(..)))
             }
         });
     }
@@ -387,8 +387,13 @@ impl VisitMut for EsModuleCollector {
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
         debug!("import decl {:#?}", import_decl);
 
+        if import_decl.specifiers.is_empty() && !import_decl.type_only {
+            debug!("side effect import: {:#?}", import_decl.src.value);
+            self.side_effect_imports.push(*import_decl.src.clone());
+        }
+
         import_decl.specifiers.iter().for_each(|import_spec| {
-            let src = import_decl.src.value.to_string();
+            let src = &import_decl.src;
             match import_spec {
                 ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
                     debug!("default import: {:#?}", local.sym);
@@ -477,14 +482,14 @@ impl VisitMut for EsModuleCollector {
                 if let Some(ExportSpecifier::Namespace(ExportNamespaceSpecifier {
                     name: ModuleExportName::Ident(module_ident),
                     ..
-                })) = specifiers.get(0)
+                })) = specifiers.first()
                 {
                     // Case 1
                     let export_ident = private_ident!("__re_export");
                     self.imports.push(ImportModule::namespace(
                         export_ident.clone(),
                         None,
-                        module_src.value.to_string(),
+                        module_src,
                         true,
                     ));
                     self.exports.push(ExportModule::named(
@@ -506,6 +511,7 @@ impl VisitMut for EsModuleCollector {
                                 ident: ident.clone(),
                                 imported: Some(orig_ident.clone()),
                                 module_src: module_src.value.to_string(),
+                                module_src_span: module_src.span,
                                 module_type: if is_default {
                                     ModuleType::DefaultAsNamed
                                 } else {
@@ -539,7 +545,7 @@ impl VisitMut for EsModuleCollector {
         self.imports.push(ImportModule::namespace(
             export_all_ident.clone(),
             None,
-            export_all.src.value.to_string(),
+            &export_all.src,
             true,
         ));
         self.exports
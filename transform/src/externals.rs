@@ -0,0 +1,351 @@
+use regex::Regex;
+use serde::Deserialize;
+use std::{cell::RefCell, collections::HashMap};
+
+#[cfg(feature = "node")]
+use crate::node_resolver::{installed_version, required_version};
+use crate::{error::ConfigError, path_resolver::dirname};
+
+/// Rule that decides whether the import source is an external module and how it is provided.
+///
+/// Sources are matched by `source`, `pattern` or `bare` and provided by
+/// `global`, `require` or the external registry (`key`, defaults to the source).
+///
+/// eg. `{ "source": "react", "global": "window.React" }`, `{ "bare": true }`
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExternalRule {
+    /// Exact import source.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Regular expression of import sources.
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Match all bare specifiers (eg. `react`, `@scope/lib/sub`).
+    #[serde(default)]
+    pub bare: bool,
+    /// Pre-existing global variable that provides the module (eg. `window.React`).
+    #[serde(default)]
+    pub global: Option<String>,
+    /// Key of the external registry.
+    #[serde(default)]
+    pub key: Option<String>,
+    /// Keep `require("source")` as is (CommonJS passthrough).
+    #[serde(default)]
+    pub require: bool,
+    /// Version of the module to register to the external registry (eg. `18.2.0`).
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Semver range of the module to request from the external registry (eg. `^18.0.0`).
+    #[serde(default)]
+    pub required_version: Option<String>,
+    /// Read `version` (installed package) and `requiredVersion` (dependencies of the nearest package.json)
+    /// from package.json when not specified.
+    ///
+    /// Requires the `node` feature.
+    #[serde(default)]
+    pub from_package_json: bool,
+}
+
+impl ExternalRule {
+    /// Rule that registers sources matched by the pattern to the external registry (`externalPattern`).
+    pub fn pattern(pattern: &str) -> Self {
+        ExternalRule {
+            pattern: Some(pattern.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Provider of the external module.
+#[derive(Clone, Debug, PartialEq)]
+pub enum External {
+    /// External registry with the key and version metadata.
+    ///
+    /// eg. `global.__modules.external("react", void 0, { requiredVersion: "^18.0.0" })`
+    Registry {
+        key: String,
+        version: Option<String>,
+        required_version: Option<String>,
+    },
+    /// Pre-existing global variable.
+    ///
+    /// eg. `window.React`
+    Global(String),
+    /// CommonJS require.
+    ///
+    /// eg. `require("react")`
+    Require,
+}
+
+struct CompiledExternalRule {
+    source: Option<String>,
+    regex: Option<Regex>,
+    bare: bool,
+    global: Option<String>,
+    key: Option<String>,
+    require: bool,
+    version: Option<String>,
+    required_version: Option<String>,
+    from_package_json: bool,
+}
+
+impl CompiledExternalRule {
+    fn is_match(&self, src: &str, is_bare: bool) -> bool {
+        self.source.as_deref() == Some(src)
+            || self.regex.as_ref().is_some_and(|regex| regex.is_match(src))
+            || (self.bare && is_bare)
+    }
+
+    fn external(&self, src: &str, package_versions: impl FnOnce() -> PackageVersions) -> External {
+        if let Some(global) = &self.global {
+            External::Global(global.clone())
+        } else if self.require {
+            External::Require
+        } else {
+            let package_versions = if self.from_package_json {
+                package_versions()
+            } else {
+                PackageVersions::default()
+            };
+
+            External::Registry {
+                key: self.key.clone().unwrap_or(src.to_string()),
+                version: self.version.clone().or(package_versions.version),
+                required_version: self
+                    .required_version
+                    .clone()
+                    .or(package_versions.required_version),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct PackageVersions {
+    version: Option<String>,
+    required_version: Option<String>,
+}
+
+/// External rules matched in the declared order (first match wins).
+pub struct ExternalRules {
+    rules: Vec<CompiledExternalRule>,
+    importer_dir: String,
+    package_versions: RefCell<HashMap<String, PackageVersions>>,
+}
+
+impl ExternalRules {
+    /// `filename` is the importing file to look up package.json files from.
+    ///
+    /// Returns an error if the `pattern` of the rule is not a valid regular expression.
+    pub fn new(rules: Vec<ExternalRule>, filename: &str) -> Result<Self, ConfigError> {
+        Ok(ExternalRules {
+            importer_dir: dirname(filename),
+            package_versions: Default::default(),
+            rules: rules
+                .into_iter()
+                .enumerate()
+                .map(|(index, rule)| {
+                    Ok(CompiledExternalRule {
+                        source: rule.source,
+                        regex: rule
+                            .pattern
+                            .map(|pattern| Regex::new(pattern.as_str()))
+                            .transpose()
+                            .map_err(|error| {
+                                ConfigError::new(format!("externals[{index}].pattern"), error)
+                            })?,
+                        bare: rule.bare,
+                        global: rule.global,
+                        key: rule.key,
+                        require: rule.require,
+                        version: rule.version,
+                        required_version: rule.required_version,
+                        from_package_json: rule.from_package_json,
+                    })
+                })
+                .collect::<Result<_, ConfigError>>()?,
+        })
+    }
+
+    /// Returns the provider of the external module.
+    ///
+    /// `is_bare` is `true` if the source is a bare specifier that is not resolved into local files.
+    pub fn external(&self, src: &str, is_bare: bool) -> Option<External> {
+        self.rules
+            .iter()
+            .find(|rule| rule.is_match(src, is_bare))
+            .map(|rule| rule.external(src, || self.package_versions(src)))
+    }
+
+    /// Returns versions of the package in package.json files (cached by the source).
+    fn package_versions(&self, src: &str) -> PackageVersions {
+        self.package_versions
+            .borrow_mut()
+            .entry(src.to_string())
+            .or_insert_with(|| read_package_versions(&self.importer_dir, src))
+            .clone()
+    }
+}
+
+#[cfg(feature = "node")]
+fn read_package_versions(importer_dir: &str, src: &str) -> PackageVersions {
+    PackageVersions {
+        version: installed_version(importer_dir, src),
+        required_version: required_version(importer_dir, src),
+    }
+}
+
+#[cfg(not(feature = "node"))]
+fn read_package_versions(_importer_dir: &str, _src: &str) -> PackageVersions {
+    PackageVersions::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(rules: &str) -> ExternalRules {
+        ExternalRules::new(serde_json::from_str(rules).unwrap(), "src/index.js").unwrap()
+    }
+
+    fn registry(key: &str) -> Option<External> {
+        Some(External::Registry {
+            key: key.into(),
+            version: None,
+            required_version: None,
+        })
+    }
+
+    #[test]
+    fn registry_keys() {
+        let rules =
+            rules(r#"[{ "pattern": "^react" }, { "source": "lodash", "key": "lodash@4" }]"#);
+
+        assert_eq!(
+            rules.external("react-native", true),
+            registry("react-native")
+        );
+        assert_eq!(rules.external("lodash", true), registry("lodash@4"));
+        assert_eq!(rules.external("lodash/fp", true), None);
+    }
+
+    #[test]
+    fn global_and_require() {
+        let rules = rules(
+            r#"[
+                { "source": "react", "global": "window.React" },
+                { "pattern": "^node:", "require": true }
+            ]"#,
+        );
+
+        assert_eq!(
+            rules.external("react", true),
+            Some(External::Global("window.React".into()))
+        );
+        assert_eq!(rules.external("node:fs", false), Some(External::Require));
+    }
+
+    #[test]
+    fn bare() {
+        let rules = rules(r#"[{ "source": "react", "global": "React" }, { "bare": true }]"#);
+
+        assert_eq!(
+            rules.external("react", true),
+            Some(External::Global("React".into()))
+        );
+        assert_eq!(
+            rules.external("@scope/lib/sub", true),
+            registry("@scope/lib/sub")
+        );
+        assert_eq!(rules.external("./Button", false), None);
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules =
+            rules(r#"[{ "pattern": "^react$", "key": "a" }, { "pattern": "^react", "key": "b" }]"#);
+
+        assert_eq!(rules.external("react", true), registry("a"));
+        assert_eq!(rules.external("react-dom", true), registry("b"));
+    }
+
+    #[test]
+    fn legacy_pattern() {
+        let rules = ExternalRules::new(
+            vec![ExternalRule::pattern("^(react|react-native)")],
+            "src/index.js",
+        )
+        .unwrap();
+
+        assert_eq!(rules.external("react", true), registry("react"));
+        assert_eq!(rules.external("lodash", true), None);
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let error = ExternalRules::new(
+            serde_json::from_str(r#"[{ "source": "react" }, { "pattern": "(react" }]"#).unwrap(),
+            "src/index.js",
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(error.option(), "externals[1].pattern");
+    }
+
+    #[test]
+    fn unknown_fields() {
+        assert!(serde_json::from_str::<ExternalRule>(r#"{ "sorce": "react" }"#).is_err());
+    }
+
+    #[test]
+    fn versions() {
+        let rules =
+            rules(r#"[{ "source": "react", "version": "18.2.0", "requiredVersion": "^18.0.0" }]"#);
+
+        assert_eq!(
+            rules.external("react", true),
+            Some(External::Registry {
+                key: "react".into(),
+                version: Some("18.2.0".into()),
+                required_version: Some("^18.0.0".into()),
+            })
+        );
+    }
+
+    #[cfg(feature = "node")]
+    #[test]
+    fn versions_from_package_json() {
+        let rules = ExternalRules::new(
+            serde_json::from_str(
+                r#"[
+                    { "source": "legacy", "requiredVersion": "0.x", "fromPackageJson": true },
+                    { "bare": true, "fromPackageJson": true }
+                ]"#,
+            )
+            .unwrap(),
+            "tests/fixture/esm/resolve/packages/src/index.js",
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules.external("lib/sub", true),
+            Some(External::Registry {
+                key: "lib/sub".into(),
+                version: Some("1.2.3".into()),
+                required_version: Some("^1.0.0".into()),
+            })
+        );
+        assert_eq!(
+            rules.external("legacy", true),
+            Some(External::Registry {
+                key: "legacy".into(),
+                version: Some("0.1.0".into()),
+                required_version: Some("0.x".into()),
+            })
+        );
+        assert_eq!(rules.external("missing", true), registry("missing"));
+    }
+}
@@ -0,0 +1,1260 @@
+use std::iter;
+
+use serde::Deserialize;
+use swc_core::{
+    common::{
+        collections::AHashSet, errors::HANDLER, util::take::Take, EqIgnoreSpan, Span, DUMMY_SP,
+    },
+    ecma::{
+        ast::*,
+        utils::{collect_decls, find_pat_ids, private_ident, quote_ident, ExprFactory},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    constants::{
+        CJS_API_NAME, CJS_MARKER_NAME, DEFINE_API_NAME, ESM_API_NAME, HELPER_AS_WILDCARD_NAME,
+        HOT_API_NAME, METRO_DEFINE_NAME, MODULE_EXTERNAL_NAME, MODULE_IMPORT_NAME,
+        MODULE_REQUIRE_NAME, SYSTEM_NAME, SYSTEM_REGISTER_NAME,
+    },
+    helpers::{obj_lit, obj_member_expr},
+    registry::ModuleRegistry,
+};
+
+/// Output format of the module.
+///
+/// - `"register"`: Register the module to the registry at top level (`global.__modules.esm(...)`)
+/// - `"define"`: Wrap the module with a factory (`global.__modules.define(id, meta, factory)`)
+/// - `"system"`: Register the module to SystemJS (`System.register(id, deps, declare)`)
+/// - `"metro"`: Define the module for the Metro runtime (`__d(factory, id, deps)`)
+/// - `"iife"`: Wrap the module with a function scope (`(function() { ... })()`)
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleFormat {
+    #[default]
+    Register,
+    Define,
+    System,
+    Metro,
+    Iife,
+}
+
+/// Wraps the module body with the factory that can be evaluated lazily and re-executed.
+///
+/// ```js
+/// global.__modules.define("module_id", { deps, externals }, (require, exports, hot, module) => {
+///   const _react = require("react");
+///   ...
+///   exports({ App });
+/// });
+/// ```
+///
+/// - `global.__modules.import(id)` to `require(id)`
+/// - `global.__modules.require(id)` to `require.cjs(id)`
+/// - `global.__modules.esm(id, exports, meta, ...reExports)` to `exports(exports, ...reExports)`
+/// - `global.__modules.cjs(id, meta)` to `exports.cjs()`
+/// - `global.__modules.hot(id)` to `hot`
+/// - `exports` of CommonJS modules to `module.exports` (`module` is the factory parameter)
+pub struct DefineTransformer<'a> {
+    registry: &'a ModuleRegistry,
+    require_ident: Ident,
+    exports_ident: Ident,
+    hot_ident: Ident,
+    decls: AHashSet<Id>,
+}
+
+impl<'a> DefineTransformer<'a> {
+    pub fn new(registry: &'a ModuleRegistry) -> Self {
+        DefineTransformer {
+            registry,
+            require_ident: private_ident!("require"),
+            exports_ident: private_ident!("exports"),
+            hot_ident: private_ident!("hot"),
+            decls: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the identifier is the free `exports` of CommonJS modules.
+    fn is_cjs_exports(&self, ident: &Ident) -> bool {
+        ident.sym == "exports"
+            && ident.to_id() != self.exports_ident.to_id()
+            && !self.decls.contains(&ident.to_id())
+    }
+
+    /// Returns the statement that defines the module with the factory of the statements.
+    pub fn define_stmt(self, module_id: &str, meta: Expr, stmts: Vec<Stmt>) -> Stmt {
+        let factory = ArrowExpr {
+            span: DUMMY_SP,
+            params: vec![
+                self.require_ident.into(),
+                self.exports_ident.into(),
+                self.hot_ident.into(),
+                // `module` is referenced by the CommonJS module body as is.
+                quote_ident!("module").into(),
+            ],
+            body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                span: DUMMY_SP,
+                stmts,
+            })),
+            is_async: false,
+            is_generator: false,
+            type_params: None,
+            return_type: None,
+        };
+
+        self.registry
+            .api(DEFINE_API_NAME)
+            .as_call(
+                DUMMY_SP,
+                vec![module_id.as_arg(), meta.as_arg(), factory.as_arg()],
+            )
+            .into_stmt()
+    }
+}
+
+impl VisitMut for DefineTransformer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        self.decls = collect_decls(&*module);
+        module.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        if let Expr::Ident(ident) = expr {
+            if self.is_cjs_exports(ident) {
+                *expr = obj_member_expr(quote_ident!("module").into(), quote_ident!("exports"));
+            }
+            return;
+        }
+
+        let Expr::Call(CallExpr {
+            span, callee, args, ..
+        }) = expr
+        else {
+            return;
+        };
+
+        if self.registry.is_api(callee, MODULE_IMPORT_NAME) {
+            *expr = self.require_ident.clone().as_call(*span, args.clone());
+        } else if self.registry.is_api(callee, MODULE_REQUIRE_NAME) {
+            *expr = obj_member_expr(
+                self.require_ident.clone().into(),
+                quote_ident!(CJS_API_NAME),
+            )
+            .as_call(*span, args.clone());
+        } else if self.registry.is_api(callee, ESM_API_NAME) {
+            // Exclude the module id and metadata arguments.
+            let args = args
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != 0 && *index != 2)
+                .map(|(_, arg)| arg.clone())
+                .collect();
+            *expr = self.exports_ident.clone().as_call(*span, args);
+        } else if self.registry.is_api(callee, CJS_API_NAME) {
+            *expr = obj_member_expr(
+                self.exports_ident.clone().into(),
+                quote_ident!(CJS_API_NAME),
+            )
+            .as_call(*span, vec![]);
+        } else if self.registry.is_api(callee, HOT_API_NAME) {
+            *expr = self.hot_ident.clone().into();
+        }
+    }
+}
+
+/// Wraps the module body with the SystemJS declaration.
+///
+/// ```js
+/// System.register("module_id", ["./Button", "react"], function(_export, _context) {
+///   var ___Button, _react, Button, useState, count;
+///   function App() { ... }
+///   _export("App", App);
+///   return {
+///     setters: [function(_m) { ___Button = _m; Button = _m.Button; }, function(_m) { ... }],
+///     execute: function() {
+///       _export("count", count = 0);
+///       ...
+///     }
+///   };
+/// });
+/// ```
+///
+/// Exports are live bindings so that circular dependencies get the current values.
+///
+/// - Top-level declarations are hoisted to the declaration (functions are exported before the execution)
+/// - `const ident = global.__modules.import(id)` to the dependency and its setter
+///   (imported bindings are assigned by the setter, `export * from` re-exports the dependency)
+/// - Assignments to the exported bindings to `_export(name, value)`
+/// - `global.__modules.import(id)`, `global.__modules.require(id)` to the dependency
+/// - `global.__modules.external(key)` to the dependency of the registry key
+///   (resolved by SystemJS, eg. with import maps, and `requiredVersion` is not checked)
+/// - `const ctx = global.__modules.cjs(id, meta)` to `const ctx = { exports: { __cjs: true } }`
+///   and `_export(ctx.exports)` at the end of the execution
+/// - `exports` of CommonJS modules to `module.exports` (`module` is declared in the declaration)
+///
+/// Other APIs of the global module runtime (eg. `hot` and `helpers`) are reported as errors.
+pub struct SystemTransformer<'a> {
+    registry: &'a ModuleRegistry,
+    export_ident: Ident,
+    module_ident: Ident,
+    value_ident: Option<Ident>,
+    deps: Vec<SystemDependency>,
+    // Local bindings and their exported names.
+    exports: Vec<(Id, String)>,
+    // Hoisted variables (imported bindings and top-level declarations).
+    vars: Vec<Ident>,
+    decls: AHashSet<Id>,
+    cjs: bool,
+}
+
+struct SystemDependency {
+    id: String,
+    ident: Ident,
+    setter_stmts: Vec<Stmt>,
+}
+
+impl<'a> SystemTransformer<'a> {
+    pub fn new(registry: &'a ModuleRegistry) -> Self {
+        SystemTransformer {
+            registry,
+            export_ident: private_ident!("_export"),
+            module_ident: private_ident!("_m"),
+            value_ident: None,
+            deps: Vec::new(),
+            exports: Vec::new(),
+            vars: Vec::new(),
+            decls: Default::default(),
+            cjs: false,
+        }
+    }
+
+    /// Returns the statement that registers the module to SystemJS with the statements.
+    pub fn register_stmt(mut self, module_id: &str, stmts: Vec<Stmt>) -> Stmt {
+        let mut re_export_all = Vec::new();
+        if let Some(esm_args) = stmts
+            .iter()
+            .find_map(|stmt| esm_call_args(self.registry, stmt))
+        {
+            let mut args = esm_args.into_iter().skip(1);
+            if let Some(Expr::Object(ObjectLit { props, .. })) = args.next().map(|arg| *arg.expr) {
+                self.exports = props.iter().filter_map(export_prop).collect();
+            }
+            re_export_all = args
+                .skip(1)
+                .filter_map(|arg| arg.expr.ident().map(|ident| ident.to_id()))
+                .collect();
+        }
+        self.decls = collect_decls(&stmts);
+        self.cjs = stmts.iter().any(|stmt| {
+            as_var_init(stmt).is_some_and(|(_, init)| self.is_api_call(init, CJS_API_NAME))
+        });
+
+        let mut fn_stmts = Vec::new();
+        let mut execute_stmts = Vec::with_capacity(stmts.len());
+        let mut cjs_exports = Vec::new();
+
+        for mut stmt in stmts {
+            if esm_call_args(self.registry, &stmt).is_some() || self.convert_dep_decl(&stmt) {
+                continue;
+            }
+            if let Some((ident, dep_ident)) = self.as_wildcard_decl(&stmt) {
+                if re_export_all.contains(&ident.to_id()) {
+                    self.re_export_all(&dep_ident);
+                } else {
+                    let module_ident = self.module_ident.clone();
+                    self.add_import_binding(&dep_ident, ident, module_ident.into());
+                }
+                continue;
+            }
+
+            if let Some(cjs_ident) = self.convert_cjs_decl(&mut stmt) {
+                cjs_exports.push(
+                    self.export_all_stmt(obj_member_expr(
+                        cjs_ident.into(),
+                        quote_ident!("exports"),
+                    )),
+                );
+            }
+
+            stmt.visit_mut_with(&mut self);
+            match stmt {
+                Stmt::Decl(Decl::Fn(FnDecl { ref ident, .. })) => {
+                    let export_stmts = self.export_stmts(ident);
+                    fn_stmts.push(stmt);
+                    fn_stmts.extend(export_stmts);
+                }
+                Stmt::Decl(Decl::Var(var_decl)) => {
+                    execute_stmts.extend(self.hoist_var_decl(*var_decl))
+                }
+                Stmt::Decl(Decl::Class(ClassDecl { ident, class, .. })) => {
+                    self.vars.push(ident.clone());
+                    let class_expr = Expr::from(ClassExpr {
+                        ident: Some(ident.clone()),
+                        class,
+                    });
+                    execute_stmts.push(
+                        self.export_expr(
+                            &ident.to_id(),
+                            class_expr.make_assign_to(AssignOp::Assign, ident.into()),
+                        )
+                        .into_stmt(),
+                    );
+                }
+                stmt => execute_stmts.push(stmt),
+            }
+        }
+        execute_stmts.extend(cjs_exports);
+
+        let module_ident = self.module_ident.clone();
+        let setters = self
+            .deps
+            .iter_mut()
+            .map(|dep| {
+                let mut setter_stmts = vec![module_ident
+                    .clone()
+                    .make_assign_to(AssignOp::Assign, dep.ident.clone().into())
+                    .into_stmt()];
+                setter_stmts.append(&mut dep.setter_stmts);
+                Some(function_expr(vec![module_ident.clone()], setter_stmts).as_arg())
+            })
+            .collect();
+
+        let mut declare_stmts = Vec::new();
+        let vars = self
+            .deps
+            .iter()
+            .map(|dep| dep.ident.clone())
+            .chain(self.vars.iter().cloned())
+            .chain(self.value_ident.iter().cloned())
+            .map(|ident| (ident, None))
+            .collect::<Vec<_>>();
+        if !vars.is_empty() {
+            declare_stmts.push(var_stmt(vars));
+        }
+        if self.cjs && !self.decls.contains(&quote_ident!("module").to_id()) {
+            declare_stmts.push(var_stmt(vec![(
+                quote_ident!("module"),
+                Some(obj_lit(Some(vec![key_value_prop(
+                    "exports",
+                    obj_lit(None),
+                )]))),
+            )]));
+        }
+        declare_stmts.extend(fn_stmts);
+        declare_stmts.push(Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(obj_lit(Some(vec![
+                key_value_prop(
+                    "setters",
+                    ArrayLit {
+                        span: DUMMY_SP,
+                        elems: setters,
+                    }
+                    .into(),
+                ),
+                key_value_prop("execute", function_expr(vec![], execute_stmts)),
+            ])))),
+        }));
+
+        let deps = ArrayLit {
+            span: DUMMY_SP,
+            elems: self
+                .deps
+                .iter()
+                .map(|dep| Some(dep.id.as_str().as_arg()))
+                .collect(),
+        };
+        let declare = function_expr(
+            vec![self.export_ident, private_ident!("_context")],
+            declare_stmts,
+        );
+
+        obj_member_expr(
+            quote_ident!(SYSTEM_NAME).into(),
+            quote_ident!(SYSTEM_REGISTER_NAME),
+        )
+        .as_call(
+            DUMMY_SP,
+            vec![module_id.as_arg(), deps.as_arg(), declare.as_arg()],
+        )
+        .into_stmt()
+    }
+
+    /// Returns the identifier of the dependency (registered once per module id).
+    fn get_dep_ident(&mut self, id: &str) -> Ident {
+        if let Some(dep) = self.deps.iter().find(|dep| dep.id == id) {
+            return dep.ident.clone();
+        }
+        let ident = private_ident!("__dep");
+        self.add_dep(id.to_string(), ident.clone());
+        ident
+    }
+
+    fn add_dep(&mut self, id: String, ident: Ident) {
+        self.deps.push(SystemDependency {
+            id,
+            ident,
+            setter_stmts: Vec::new(),
+        });
+    }
+
+    /// Returns `true` if the expression is the registry API call (eg. `global.__modules.import("id")`).
+    fn is_api_call(&self, expr: &Expr, name: &str) -> bool {
+        api_call_id(self.registry, expr, name).is_some()
+    }
+
+    /// Registers `const ident = global.__modules.import("id")` as the dependency
+    /// (and `global.__modules.external("key")` as the dependency of the registry key).
+    fn convert_dep_decl(&mut self, stmt: &Stmt) -> bool {
+        let Some((ident, init)) = as_var_init(stmt) else {
+            return false;
+        };
+        let Some(id) = api_call_id(self.registry, init, MODULE_IMPORT_NAME)
+            .or_else(|| api_call_id(self.registry, init, MODULE_EXTERNAL_NAME))
+        else {
+            return false;
+        };
+        self.add_dep(id, ident.clone());
+        true
+    }
+
+    /// `const ident = global.__modules.helpers.asWildcard(dep)`
+    fn as_wildcard_decl(&self, stmt: &Stmt) -> Option<(Ident, Ident)> {
+        let (ident, dep_ident) = as_wildcard_decl(self.registry, stmt)?;
+        self.deps
+            .iter()
+            .any(|dep| dep.ident.to_id() == dep_ident.to_id())
+            .then(|| (ident.clone(), dep_ident.clone()))
+    }
+
+    /// `const ident = dep.name` (imported bindings)
+    fn convert_import_binding(&mut self, var_decl: &VarDecl) -> bool {
+        let [VarDeclarator {
+            name: Pat::Ident(BindingIdent { id, .. }),
+            init: Some(init),
+            ..
+        }] = var_decl.decls.as_slice()
+        else {
+            return false;
+        };
+        let Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) = &**init
+        else {
+            return false;
+        };
+        let Some(dep_ident) = obj.as_ident() else {
+            return false;
+        };
+        if !self
+            .deps
+            .iter()
+            .any(|dep| dep.ident.to_id() == dep_ident.to_id())
+        {
+            return false;
+        }
+
+        let value = obj_member_expr(self.module_ident.clone().into(), prop.clone());
+        self.add_import_binding(&dep_ident.clone(), id.clone(), value);
+        true
+    }
+
+    /// Hoists the imported binding and assigns the value in the setter of the dependency.
+    fn add_import_binding(&mut self, dep_ident: &Ident, ident: Ident, value: Expr) {
+        let stmt = self
+            .export_expr(
+                &ident.to_id(),
+                value.make_assign_to(AssignOp::Assign, ident.clone().into()),
+            )
+            .into_stmt();
+        self.vars.push(ident);
+        if let Some(dep) = self
+            .deps
+            .iter_mut()
+            .find(|dep| dep.ident.to_id() == dep_ident.to_id())
+        {
+            dep.setter_stmts.push(stmt);
+        }
+    }
+
+    /// Re-exports the dependency except `default` and the names exported by the module.
+    ///
+    /// ```js
+    /// var _exportObj = {};
+    /// for (var _key in _m) {
+    ///   if (_key !== "default" && _key !== "App") _exportObj[_key] = _m[_key];
+    /// }
+    /// _export(_exportObj);
+    /// ```
+    fn re_export_all(&mut self, dep_ident: &Ident) {
+        let export_obj_ident = private_ident!("_exportObj");
+        let except = iter::once("default")
+            .chain(
+                self.exports
+                    .iter()
+                    .map(|(_, name)| name.as_str())
+                    .filter(|name| *name != "default"),
+            )
+            .collect::<Vec<_>>();
+        let mut stmts = copy_props_stmts(&export_obj_ident, &self.module_ident, &except);
+        stmts.push(self.export_all_stmt(export_obj_ident.into()));
+        if let Some(dep) = self
+            .deps
+            .iter_mut()
+            .find(|dep| dep.ident.to_id() == dep_ident.to_id())
+        {
+            dep.setter_stmts.extend(stmts);
+        }
+    }
+
+    /// Converts `const ctx = global.__modules.cjs(id, meta)` to `const ctx = { exports: { __cjs: true } }`
+    /// and returns the identifier of the context.
+    fn convert_cjs_decl(&self, stmt: &mut Stmt) -> Option<Ident> {
+        let Stmt::Decl(Decl::Var(var_decl)) = stmt else {
+            return None;
+        };
+        let [VarDeclarator {
+            name: Pat::Ident(BindingIdent { id, .. }),
+            init: Some(init),
+            ..
+        }] = var_decl.decls.as_mut_slice()
+        else {
+            return None;
+        };
+        if !self.is_api_call(init, CJS_API_NAME) {
+            return None;
+        }
+
+        **init = obj_lit(Some(vec![key_value_prop(
+            "exports",
+            obj_lit(Some(vec![key_value_prop(
+                CJS_MARKER_NAME,
+                Expr::from(true),
+            )])),
+        )]));
+        Some(id.clone())
+    }
+
+    /// Hoists the bindings of the variable declaration and returns the statements of the assignments.
+    ///
+    /// eg. `const count = 0` to `var count` and `_export("count", count = 0)`
+    fn hoist_var_decl(&mut self, var_decl: VarDecl) -> Vec<Stmt> {
+        if self.convert_import_binding(&var_decl) {
+            return Vec::new();
+        }
+
+        let mut stmts = Vec::new();
+        for VarDeclarator { name, init, .. } in var_decl.decls {
+            let ids: Vec<Ident> = find_pat_ids(&name);
+            self.vars.extend(ids.iter().cloned());
+            let Some(init) = init else {
+                continue;
+            };
+            match name {
+                Pat::Ident(BindingIdent { id, .. }) => stmts.push(
+                    self.export_expr(
+                        &id.to_id(),
+                        init.make_assign_to(AssignOp::Assign, id.into()),
+                    )
+                    .into_stmt(),
+                ),
+                pat => {
+                    let Ok(left) = AssignTarget::try_from(pat) else {
+                        continue;
+                    };
+                    stmts.push(
+                        Expr::Paren(ParenExpr {
+                            span: DUMMY_SP,
+                            expr: Box::new(init.make_assign_to(AssignOp::Assign, left)),
+                        })
+                        .into_stmt(),
+                    );
+                    ids.iter()
+                        .for_each(|ident| stmts.extend(self.export_stmts(ident)));
+                }
+            }
+        }
+        stmts
+    }
+
+    /// Returns `true` if the binding is exported.
+    fn is_exported(&self, id: &Id) -> bool {
+        self.exports.iter().any(|(local, _)| local == id)
+    }
+
+    /// `_export("name", expr)` for each exported name of the binding.
+    fn export_expr(&self, id: &Id, expr: Expr) -> Expr {
+        self.exports
+            .iter()
+            .filter(|(local, _)| local == id)
+            .fold(expr, |expr, (_, name)| {
+                self.export_ident
+                    .clone()
+                    .as_call(DUMMY_SP, vec![name.as_str().as_arg(), expr.as_arg()])
+            })
+    }
+
+    /// `_export("name", ident)` for each exported name of the binding.
+    fn export_stmts(&self, ident: &Ident) -> Vec<Stmt> {
+        self.exports
+            .iter()
+            .filter(|(local, _)| *local == ident.to_id())
+            .map(|(_, name)| {
+                self.export_ident
+                    .clone()
+                    .as_call(
+                        DUMMY_SP,
+                        vec![name.as_str().as_arg(), ident.clone().as_arg()],
+                    )
+                    .into_stmt()
+            })
+            .collect()
+    }
+
+    /// `(_value = expr, _export("name", ident), ..., _value)`
+    fn export_seq_expr(&mut self, idents: Vec<Ident>, expr: Expr) -> Expr {
+        let value_ident = self
+            .value_ident
+            .get_or_insert_with(|| private_ident!("_value"))
+            .clone();
+        let mut exprs = vec![Box::new(
+            expr.make_assign_to(AssignOp::Assign, value_ident.clone().into()),
+        )];
+        idents.iter().for_each(|ident| {
+            exprs.extend(
+                self.export_stmts(ident)
+                    .into_iter()
+                    .filter_map(|stmt| stmt.expr().map(|expr_stmt| expr_stmt.expr)),
+            )
+        });
+        exprs.push(Box::new(value_ident.into()));
+        Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs,
+        })
+    }
+
+    /// `_export(expr)`
+    fn export_all_stmt(&self, expr: Expr) -> Stmt {
+        self.export_ident
+            .clone()
+            .as_call(DUMMY_SP, vec![expr.as_arg()])
+            .into_stmt()
+    }
+}
+
+impl VisitMut for SystemTransformer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr_stmt(&mut self, expr_stmt: &mut ExprStmt) {
+        // The value is not used (eg. `count++;` to `_export("count", ++count);`).
+        if let Expr::Update(UpdateExpr { prefix, arg, .. }) = &mut *expr_stmt.expr {
+            if arg
+                .as_ident()
+                .is_some_and(|ident| self.is_exported(&ident.to_id()))
+            {
+                *prefix = true;
+            }
+        }
+        expr_stmt.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Some(id) = api_call_id(self.registry, expr, MODULE_IMPORT_NAME)
+            .or_else(|| api_call_id(self.registry, expr, MODULE_EXTERNAL_NAME))
+        {
+            *expr = self.get_dep_ident(&id).into();
+            return;
+        }
+        if let Some(id) = api_call_id(self.registry, expr, MODULE_REQUIRE_NAME) {
+            // `dep.__cjs ? dep.default : dep`
+            let dep_ident = self.get_dep_ident(&id);
+            *expr = Expr::Cond(CondExpr {
+                span: DUMMY_SP,
+                test: Box::new(obj_member_expr(
+                    dep_ident.clone().into(),
+                    quote_ident!(CJS_MARKER_NAME),
+                )),
+                cons: Box::new(obj_member_expr(
+                    dep_ident.clone().into(),
+                    quote_ident!("default"),
+                )),
+                alt: Box::new(dep_ident.into()),
+            });
+            return;
+        }
+        if let Expr::Call(CallExpr {
+            span,
+            callee: Callee::Expr(callee),
+            ..
+        }) = expr
+        {
+            if let Some(name) = runtime_api_name(self.registry, callee) {
+                report_runtime_api(*span, &name, "system");
+                return;
+            }
+        }
+
+        expr.visit_mut_children_with(self);
+
+        match expr {
+            Expr::Ident(ident)
+                if self.cjs && ident.sym == "exports" && !self.decls.contains(&ident.to_id()) =>
+            {
+                *expr = obj_member_expr(quote_ident!("module").into(), quote_ident!("exports"));
+            }
+            Expr::Assign(AssignExpr { left, .. }) => match left {
+                AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. })) => {
+                    let id = id.to_id();
+                    if self.is_exported(&id) {
+                        *expr = self.export_expr(&id, expr.take());
+                    }
+                }
+                AssignTarget::Pat(pat) => {
+                    let idents: Vec<Ident> = find_pat_ids(pat);
+                    let idents: Vec<Ident> = idents
+                        .into_iter()
+                        .filter(|ident| self.is_exported(&ident.to_id()))
+                        .collect();
+                    if !idents.is_empty() {
+                        *expr = self.export_seq_expr(idents, expr.take());
+                    }
+                }
+                _ => {}
+            },
+            Expr::Update(UpdateExpr { prefix, arg, .. }) => {
+                let Some(ident) = arg
+                    .as_ident()
+                    .filter(|ident| self.is_exported(&ident.to_id()))
+                else {
+                    return;
+                };
+                *expr = if *prefix {
+                    self.export_expr(&ident.to_id(), expr.take())
+                } else {
+                    // `count++` evaluates to the previous value.
+                    let ident = ident.clone();
+                    self.export_seq_expr(vec![ident], expr.take())
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the local binding and the exported name of the export property.
+///
+/// eg. `App` (`{ App }`), `__export_default` and `default` (`{ default: __export_default }`)
+fn export_prop(prop: &PropOrSpread) -> Option<(Id, String)> {
+    match prop.as_prop().map(|prop| &**prop)? {
+        Prop::Shorthand(ident) => Some((ident.to_id(), ident.sym.to_string())),
+        Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(key),
+            value,
+        }) => value
+            .as_ident()
+            .map(|ident| (ident.to_id(), key.sym.to_string())),
+        _ => None,
+    }
+}
+
+/// `const ident = init`
+fn as_var_init(stmt: &Stmt) -> Option<(&Ident, &Expr)> {
+    let Stmt::Decl(Decl::Var(var_decl)) = stmt else {
+        return None;
+    };
+    match var_decl.decls.as_slice() {
+        [VarDeclarator {
+            name: Pat::Ident(BindingIdent { id, .. }),
+            init: Some(init),
+            ..
+        }] => Some((id, init)),
+        _ => None,
+    }
+}
+
+/// `const ident = global.__modules.helpers.asWildcard(dep)`
+fn as_wildcard_decl<'a>(
+    registry: &ModuleRegistry,
+    stmt: &'a Stmt,
+) -> Option<(&'a Ident, &'a Ident)> {
+    let (ident, init) = as_var_init(stmt)?;
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = init
+    else {
+        return None;
+    };
+    if !(**callee).eq_ignore_span(&registry.helper(HELPER_AS_WILDCARD_NAME)) {
+        return None;
+    }
+    Some((ident, args.first()?.expr.as_ident()?))
+}
+
+/// Copies the properties except the names.
+///
+/// ```js
+/// var to = {};
+/// for (var _key in from) {
+///   if (_key !== "default") to[_key] = from[_key];
+/// }
+/// ```
+fn copy_props_stmts(to: &Ident, from: &Ident, except: &[&str]) -> Vec<Stmt> {
+    let key_ident = private_ident!("_key");
+    let computed = |obj: &Ident| {
+        Expr::from(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(obj.clone().into()),
+            prop: MemberProp::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: Box::new(key_ident.clone().into()),
+            }),
+        })
+    };
+    let copy_stmt = computed(from)
+        .make_assign_to(AssignOp::Assign, computed(to).expect_member().into())
+        .into_stmt();
+    let test = except
+        .iter()
+        .map(|name| {
+            Box::new(Expr::from(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::NotEqEq,
+                left: Box::new(key_ident.clone().into()),
+                right: Box::new((*name).into()),
+            }))
+        })
+        .reduce(|left, right| {
+            Box::new(Expr::from(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::LogicalAnd,
+                left,
+                right,
+            }))
+        });
+    let copy_stmt = match test {
+        Some(test) => Stmt::If(IfStmt {
+            span: DUMMY_SP,
+            test,
+            cons: Box::new(copy_stmt),
+            alt: None,
+        }),
+        None => copy_stmt,
+    };
+    let for_in_stmt = Stmt::ForIn(ForInStmt {
+        span: DUMMY_SP,
+        left: ForHead::VarDecl(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: key_ident.into(),
+                init: None,
+                definite: false,
+            }],
+        })),
+        right: Box::new(from.clone().into()),
+        body: Box::new(Stmt::Block(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![copy_stmt],
+        })),
+    });
+
+    vec![
+        var_stmt(vec![(to.clone(), Some(obj_lit(None)))]),
+        for_in_stmt,
+    ]
+}
+
+/// Returns the name of the global module runtime API that the callee refers to.
+///
+/// eg. `hot` of `global.__modules.hot`, `helpers` of `global.__modules.helpers.asModule`
+fn runtime_api_name(registry: &ModuleRegistry, callee: &Expr) -> Option<String> {
+    let Expr::Member(MemberExpr { obj, prop, .. }) = callee else {
+        return None;
+    };
+    match prop {
+        MemberProp::Ident(prop) if (**obj).eq_ignore_span(&registry.expr()) => {
+            Some(prop.sym.to_string())
+        }
+        _ => runtime_api_name(registry, obj),
+    }
+}
+
+/// Reports the API that requires the global module runtime.
+fn report_runtime_api(span: Span, name: &str, format: &str) {
+    if !HANDLER.is_set() {
+        return;
+    }
+
+    let msg =
+        format!("`{name}` of the global module runtime is not supported by the `{format}` format");
+    HANDLER.with(|handler| {
+        if span.is_dummy() {
+            handler.struct_err(&msg).emit()
+        } else {
+            handler.struct_span_err(span, &msg).emit()
+        }
+    });
+}
+
+/// `var a, b = init`
+fn var_stmt(decls: Vec<(Ident, Option<Expr>)>) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls: decls
+            .into_iter()
+            .map(|(ident, init)| VarDeclarator {
+                span: DUMMY_SP,
+                name: ident.into(),
+                init: init.map(Box::new),
+                definite: false,
+            })
+            .collect(),
+    })))
+}
+
+/// Wraps the module body with the Metro module definition.
+///
+/// ```js
+/// __d(function(global, _$$_REQUIRE, _$$_IMPORT_DEFAULT, _$$_IMPORT_ALL, module, exports, _dependencyMap) {
+///   const _react = _$$_IMPORT_ALL(_dependencyMap[0]);
+///   ...
+///   Object.defineProperty(exports, "__esModule", { value: true });
+///   Object.assign(exports, { App });
+/// }, 12, [34]);
+/// ```
+///
+/// Module ids are emitted as numbers (eg. `moduleIdStrategy: "numeric"`, numeric `moduleId`, `moduleIds` and pragma ids).
+/// Ids that are not numbers are reported as errors.
+///
+/// - `global.__modules.import(id)` to `_$$_IMPORT_ALL(_dependencyMap[index])`
+///   (and `global.__modules.external(key)` to the dependency of the registry key)
+/// - `dep.default` of the imported module to `_$$_IMPORT_DEFAULT(_dependencyMap[index])`
+/// - `global.__modules.require(id)` to `_$$_REQUIRE(_dependencyMap[index])`
+/// - `const ident = global.__modules.helpers.asWildcard(dep)` to the copy of `dep` except `default`
+/// - `global.__modules.esm(id, exports, meta, ...reExports)` to
+///   `Object.assign(exports, ...reExports, exports)` with the `__esModule` flag
+/// - `global.__modules.cjs(id, meta)` to `{ exports: {} }` (`module.exports` is assigned as is)
+/// - `global.__modules.hot(id)` to `module.hot`
+///
+/// Other APIs of the global module runtime (eg. `helpers` of `global` and `require` externals) are reported as errors.
+pub struct MetroTransformer<'a> {
+    registry: &'a ModuleRegistry,
+    require_ident: Ident,
+    import_default_ident: Ident,
+    import_all_ident: Ident,
+    dependency_map_ident: Ident,
+    // Module ids of the dependencies (in order of the dependency map).
+    deps: Vec<String>,
+    // Identifiers of the imported modules and their module ids.
+    imports: Vec<(Id, String)>,
+}
+
+impl<'a> MetroTransformer<'a> {
+    pub fn new(registry: &'a ModuleRegistry) -> Self {
+        MetroTransformer {
+            registry,
+            require_ident: private_ident!("_$$_REQUIRE"),
+            import_default_ident: private_ident!("_$$_IMPORT_DEFAULT"),
+            import_all_ident: private_ident!("_$$_IMPORT_ALL"),
+            dependency_map_ident: private_ident!("_dependencyMap"),
+            deps: Vec::new(),
+            imports: Vec::new(),
+        }
+    }
+
+    /// Returns the statement that defines the module with the factory of the statements.
+    pub fn define_stmt(mut self, module_id: &str, stmts: Vec<Stmt>) -> Stmt {
+        self.imports = stmts
+            .iter()
+            .filter_map(|stmt| {
+                let (ident, init) = as_var_init(stmt)?;
+                let id = self
+                    .api_call_id(init, MODULE_IMPORT_NAME)
+                    .or_else(|| self.api_call_id(init, MODULE_EXTERNAL_NAME))?;
+                Some((ident.to_id(), id))
+            })
+            .collect();
+
+        let stmts = stmts
+            .into_iter()
+            .flat_map(|mut stmt| {
+                if let Some(esm_args) = esm_call_args(self.registry, &stmt) {
+                    return self.esm_export_stmts(esm_args);
+                }
+                if let Some((ident, dep_ident)) = as_wildcard_decl(self.registry, &stmt) {
+                    return copy_props_stmts(ident, dep_ident, &["default"]);
+                }
+                stmt.visit_mut_with(&mut self);
+                vec![stmt]
+            })
+            .collect();
+
+        // `global`, `module` and `exports` are referenced by the module body as is.
+        let factory = function_expr(
+            vec![
+                quote_ident!("global"),
+                self.require_ident,
+                self.import_default_ident,
+                self.import_all_ident,
+                quote_ident!("module"),
+                quote_ident!("exports"),
+                self.dependency_map_ident,
+            ],
+            stmts,
+        );
+        let deps = ArrayLit {
+            span: DUMMY_SP,
+            elems: self
+                .deps
+                .iter()
+                .map(|id| Some(module_id_expr(id).as_arg()))
+                .collect(),
+        };
+
+        quote_ident!(METRO_DEFINE_NAME)
+            .as_call(
+                DUMMY_SP,
+                vec![
+                    factory.as_arg(),
+                    module_id_expr(module_id).as_arg(),
+                    deps.as_arg(),
+                ],
+            )
+            .into_stmt()
+    }
+
+    /// Returns the dependency map entry of the module id (eg. `_dependencyMap[0]`).
+    fn dependency(&mut self, id: &str) -> Expr {
+        let index = self
+            .deps
+            .iter()
+            .position(|dep_id| dep_id == id)
+            .unwrap_or_else(|| {
+                self.deps.push(id.to_string());
+                self.deps.len() - 1
+            });
+        MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(self.dependency_map_ident.clone().into()),
+            prop: MemberProp::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::from(index as f64)),
+            }),
+        }
+        .into()
+    }
+
+    /// ```js
+    /// Object.defineProperty(exports, "__esModule", { value: true });
+    /// Object.assign(exports, ...reExports, exports);
+    /// ```
+    fn esm_export_stmts(&mut self, esm_args: Vec<ExprOrSpread>) -> Vec<Stmt> {
+        let object_api =
+            |name: &str| obj_member_expr(quote_ident!("Object").into(), quote_ident!(name));
+
+        // Re-exports are overridden by the named exports.
+        let mut args = esm_args.into_iter().skip(1);
+        let exports = args.next().into_iter();
+        let mut assign_args = vec![quote_ident!("exports").as_arg()];
+        assign_args.extend(args.skip(1).chain(exports));
+        assign_args.visit_mut_with(self);
+
+        vec![
+            object_api("defineProperty")
+                .as_call(
+                    DUMMY_SP,
+                    vec![
+                        quote_ident!("exports").as_arg(),
+                        "__esModule".as_arg(),
+                        obj_lit(Some(vec![key_value_prop("value", Expr::from(true))])).as_arg(),
+                    ],
+                )
+                .into_stmt(),
+            object_api("assign")
+                .as_call(DUMMY_SP, assign_args)
+                .into_stmt(),
+        ]
+    }
+
+    /// Returns the module id of the registry API call (eg. `global.__modules.import("id")`).
+    fn api_call_id(&self, expr: &Expr, name: &str) -> Option<String> {
+        api_call_id(self.registry, expr, name)
+    }
+
+    /// Returns the module id of the default import (eg. `_react.default`).
+    fn default_import_id(&self, expr: &Expr) -> Option<String> {
+        let Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) = expr
+        else {
+            return None;
+        };
+        let ident = obj.as_ident().filter(|_| prop.sym == "default")?;
+        self.imports
+            .iter()
+            .find(|(id, _)| *id == ident.to_id())
+            .map(|(_, module_id)| module_id.clone())
+    }
+}
+
+impl VisitMut for MetroTransformer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        if let Some(id) = self
+            .api_call_id(expr, MODULE_IMPORT_NAME)
+            .or_else(|| self.api_call_id(expr, MODULE_EXTERNAL_NAME))
+        {
+            let dependency = self.dependency(&id);
+            *expr = self
+                .import_all_ident
+                .clone()
+                .as_call(DUMMY_SP, vec![dependency.as_arg()]);
+        } else if let Some(id) = self.default_import_id(expr) {
+            let dependency = self.dependency(&id);
+            *expr = self
+                .import_default_ident
+                .clone()
+                .as_call(DUMMY_SP, vec![dependency.as_arg()]);
+        } else if let Some(id) = self.api_call_id(expr, MODULE_REQUIRE_NAME) {
+            let dependency = self.dependency(&id);
+            *expr = self
+                .require_ident
+                .clone()
+                .as_call(DUMMY_SP, vec![dependency.as_arg()]);
+        } else if self.api_call_id(expr, CJS_API_NAME).is_some() {
+            *expr = obj_lit(Some(vec![key_value_prop("exports", obj_lit(None))]));
+        } else if self.api_call_id(expr, HOT_API_NAME).is_some() {
+            *expr = obj_member_expr(quote_ident!("module").into(), quote_ident!("hot"));
+        } else if let Expr::Call(CallExpr {
+            span,
+            callee: Callee::Expr(callee),
+            ..
+        }) = expr
+        {
+            if let Some(name) = runtime_api_name(self.registry, callee) {
+                report_runtime_api(*span, &name, "metro");
+            }
+        }
+    }
+}
+
+/// Returns the module id of the registry API call (eg. `global.__modules.import("id")`).
+fn api_call_id(registry: &ModuleRegistry, expr: &Expr, name: &str) -> Option<String> {
+    let Expr::Call(CallExpr { callee, args, .. }) = expr else {
+        return None;
+    };
+    if !registry.is_api(callee, name) {
+        return None;
+    }
+    match args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Lit(Lit::Str(id))) => Some(id.value.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the statement that wraps the statements with a function scope.
+///
+/// Top-level declarations are not leaked into the global scope
+/// so that the module can be evaluated as a classic script again (eg. `eval` for hot updates).
+///
+/// ```js
+/// (function() {
+///   "use strict"; // ES modules only
+///   ...
+/// })();
+/// ```
+pub fn iife_stmt(mut stmts: Vec<Stmt>, strict: bool) -> Stmt {
+    if strict {
+        stmts.insert(0, Expr::from("use strict").into_stmt());
+    }
+    Expr::Paren(ParenExpr {
+        span: DUMMY_SP,
+        expr: Box::new(function_expr(vec![], stmts)),
+    })
+    .as_call(DUMMY_SP, vec![])
+    .into_stmt()
+}
+
+/// Returns the arguments of the registration statement.
+///
+/// eg. `global.__modules.esm(id, exports, meta, ...reExports)`
+fn esm_call_args(registry: &ModuleRegistry, stmt: &Stmt) -> Option<Vec<ExprOrSpread>> {
+    let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+        return None;
+    };
+    match &**expr {
+        Expr::Call(CallExpr { callee, args, .. }) if registry.is_api(callee, ESM_API_NAME) => {
+            Some(args.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Returns a number literal of the numeric module id (eg. `12`).
+///
+/// Module ids that are not numbers as is (eg. `007`, `src/App.tsx`) are reported as errors.
+fn module_id_expr(id: &str) -> Expr {
+    match id.parse::<u32>() {
+        Ok(numeric_id) if numeric_id.to_string() == id => Expr::from(f64::from(numeric_id)),
+        _ => {
+            if HANDLER.is_set() {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_err(&format!(
+                            "module id `{id}` is not numeric (required by the `metro` format)"
+                        ))
+                        .emit()
+                });
+            }
+            Expr::from(id)
+        }
+    }
+}
+
+fn function_expr(params: Vec<Ident>, stmts: Vec<Stmt>) -> Expr {
+    FnExpr {
+        ident: None,
+        function: Box::new(Function {
+            params: params.into_iter().map(Param::from).collect(),
+            decorators: vec![],
+            span: DUMMY_SP,
+            body: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts,
+            }),
+            is_generator: false,
+            is_async: false,
+            type_params: None,
+            return_type: None,
+        }),
+    }
+    .into()
+}
+
+fn key_value_prop(key: &str, value: Expr) -> PropOrSpread {
+    Prop::KeyValue(KeyValueProp {
+        key: quote_ident!(key).into(),
+        value: Box::new(value),
+    })
+    .into()
+}
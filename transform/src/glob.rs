@@ -0,0 +1,94 @@
+use regex::Regex;
+
+/// Glob pattern of file paths.
+///
+/// - `**`: Any directories (eg. `src/**/*.ts`, `**/node_modules/**`)
+/// - `*`: Any characters except `/`
+/// - `?`: Any character except `/`
+/// - `{a,b}`: Alternatives (eg. `*.{ts,tsx}`)
+pub struct Glob {
+    regex: Regex,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        let mut in_alternatives = false;
+
+        while let Some(char) = chars.next() {
+            match char {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    if chars.peek() == Some(&'/') {
+                        // `**/` matches zero or more directories.
+                        chars.next();
+                        regex.push_str("(?:.*/)?");
+                    } else {
+                        regex.push_str(".*");
+                    }
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                '{' if !in_alternatives => {
+                    in_alternatives = true;
+                    regex.push_str("(?:");
+                }
+                ',' if in_alternatives => regex.push('|'),
+                '}' if in_alternatives => {
+                    in_alternatives = false;
+                    regex.push(')');
+                }
+                _ => regex.push_str(&regex::escape(&char.to_string())),
+            }
+        }
+        regex.push('$');
+
+        Ok(Glob {
+            regex: Regex::new(&regex)?,
+        })
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        self.regex.is_match(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(pattern: &str, path: &str) -> bool {
+        Glob::new(pattern).unwrap().is_match(path)
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(is_match("src/*.ts", "src/index.ts"));
+        assert!(!is_match("src/*.ts", "src/a/index.ts"));
+        assert!(is_match("src/?.js", "src/a.js"));
+        assert!(!is_match("src/?.js", "src/ab.js"));
+    }
+
+    #[test]
+    fn globstars() {
+        assert!(is_match("src/**/*.ts", "src/index.ts"));
+        assert!(is_match("src/**/*.ts", "src/a/b/index.ts"));
+        assert!(is_match(
+            "**/node_modules/**",
+            "node_modules/react/index.js"
+        ));
+        assert!(is_match(
+            "**/node_modules/**",
+            "/app/node_modules/react/index.js"
+        ));
+        assert!(!is_match("**/node_modules/**", "/app/src/index.js"));
+    }
+
+    #[test]
+    fn alternatives() {
+        assert!(is_match("**/*.{test,spec}.{ts,tsx}", "src/App.test.tsx"));
+        assert!(is_match("**/*.{test,spec}.{ts,tsx}", "src/a/App.spec.ts"));
+        assert!(!is_match("**/*.{test,spec}.{ts,tsx}", "src/App.tsx"));
+    }
+}
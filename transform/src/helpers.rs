@@ -1,14 +1,19 @@
 use swc_core::{
-    common::DUMMY_SP,
+    common::{sync::Lrc, SourceMap, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::{quote_ident, ExprFactory},
+        codegen::{text_writer::JsWriter, Config, Emitter, Node},
+        utils::{quote_ident, undefined, DropSpan, ExprFactory},
+        visit::VisitMutWith,
     },
 };
 
-use crate::constants::{
-    GLOBAL, HELPER_AS_WILDCARD_NAME, MODULE, MODULE_EXTERNAL_NAME, MODULE_HELPER_NAME,
-    MODULE_IMPORT_NAME, MODULE_REQUIRE_NAME,
+use crate::{
+    constants::{
+        HELPER_AS_MODULE_NAME, HELPER_AS_WILDCARD_NAME, MODULE_EXTERNAL_NAME, MODULE_IMPORT_NAME,
+        MODULE_REQUIRE_NAME,
+    },
+    registry::ModuleRegistry,
 };
 
 /// Returns an object member expression.
@@ -45,7 +50,20 @@ pub fn decl_var_and_assign_stmt(ident: &Ident, init: Expr) -> Stmt {
 pub fn obj_lit(props: Option<Vec<PropOrSpread>>) -> Expr {
     ObjectLit {
         span: DUMMY_SP,
-        props: props.unwrap_or(Vec::new()),
+        props: props.unwrap_or_default(),
+    }
+    .into()
+}
+
+/// Returns an array literal expression of string literals.
+///
+/// eg. `["a", "b"]`
+pub fn str_array_lit<'a>(values: impl Iterator<Item = &'a String>) -> Expr {
+    ArrayLit {
+        span: DUMMY_SP,
+        elems: values
+            .map(|value| Some(Expr::from(value.as_str()).as_arg()))
+            .collect(),
     }
     .into()
 }
@@ -53,57 +71,90 @@ pub fn obj_lit(props: Option<Vec<PropOrSpread>>) -> Expr {
 /// Returns an statement that import module from global.
 ///
 /// eg. `global.__modules.import('module_id')`
-pub fn import_module_from_global(src: &String) -> Expr {
-    obj_member_expr(
-        obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-        quote_ident!(MODULE_IMPORT_NAME),
-    )
-    .as_call(
-        DUMMY_SP,
-        vec![Expr::Lit(Lit::Str(Str::from(src.as_str()))).as_arg()],
-    )
+pub fn import_module_from_global(registry: &ModuleRegistry, src: &str) -> Expr {
+    registry
+        .api(MODULE_IMPORT_NAME)
+        .as_call(DUMMY_SP, vec![Expr::Lit(Lit::Str(Str::from(src))).as_arg()])
 }
 
 /// Returns an statement that require module from global.
 ///
 /// eg. `global.__modules.require('module_id')`
-pub fn require_module_from_global(src: &String) -> Expr {
-    obj_member_expr(
-        obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-        quote_ident!(MODULE_REQUIRE_NAME),
-    )
-    .as_call(
-        DUMMY_SP,
-        vec![Expr::Lit(Lit::Str(Str::from(src.as_str()))).as_arg()],
-    )
+pub fn require_module_from_global(registry: &ModuleRegistry, src: &str) -> Expr {
+    registry
+        .api(MODULE_REQUIRE_NAME)
+        .as_call(DUMMY_SP, vec![Expr::Lit(Lit::Str(Str::from(src))).as_arg()])
 }
 
 /// Returns an statement that require module from global.
 ///
 /// eg. `global.__modules.external('module_src')`
-pub fn external_module_from_global(module_src: &String) -> Expr {
-    obj_member_expr(
-        obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-        quote_ident!(MODULE_EXTERNAL_NAME),
-    )
-    .as_call(
+/// eg. `global.__modules.external('module_src', void 0, { requiredVersion: '^1.0.0' })`
+pub fn external_module_from_global(
+    registry: &ModuleRegistry,
+    module_src: &str,
+    required_version: Option<&str>,
+) -> Expr {
+    let mut args = vec![Expr::Lit(Lit::Str(Str::from(module_src))).as_arg()];
+    if let Some(required_version) = required_version {
+        args.push(undefined(DUMMY_SP).as_arg());
+        args.push(version_options_lit("requiredVersion", required_version).as_arg());
+    }
+
+    registry.api(MODULE_EXTERNAL_NAME).as_call(DUMMY_SP, args)
+}
+
+/// Returns an object literal of the version option.
+///
+/// eg. `{ version: '1.0.0' }`
+pub fn version_options_lit(key: &str, version: &str) -> Expr {
+    obj_lit(Some(vec![Prop::KeyValue(KeyValueProp {
+        key: quote_ident!(key).into(),
+        value: Expr::from(version).into(),
+    })
+    .into()]))
+}
+
+/// Returns an expression of the global variable path.
+///
+/// eg. `window.React`
+pub fn global_var_expr(path: &str) -> Expr {
+    let mut segments = path.split('.');
+    let obj: Expr = quote_ident!(segments.next().unwrap_or_default()).into();
+    segments.fold(obj, |obj, prop| obj_member_expr(obj, quote_ident!(prop)))
+}
+
+/// Returns an expression that require module with CommonJS require.
+///
+/// eg. `require('module_src')`
+pub fn require_call_expr(module_src: &str) -> Expr {
+    quote_ident!("require").as_call(
         DUMMY_SP,
-        vec![Expr::Lit(Lit::Str(Str::from(module_src.as_str()))).as_arg()],
+        vec![Expr::Lit(Lit::Str(Str::from(module_src))).as_arg()],
     )
 }
 
+/// Returns an expression that wrap the non-ES module value as module.
+///
+/// eg. `global.__modules.helpers.asModule(window.React)`
+pub fn as_module_expr(registry: &ModuleRegistry, value: Expr) -> Expr {
+    registry
+        .helper(HELPER_AS_MODULE_NAME)
+        .as_call(DUMMY_SP, vec![value.as_arg()])
+}
+
 /// Create unique module identifier and returns a statement that import default value from global.
 ///
 /// eg. `const ident = {module_ident}.default`
 /// eg. `import ident from "module_src"`
 pub fn create_default_import_stmt(
-    module_src: &String,
+    module_src: &str,
     ident: &Ident,
     runtime_module_ident: Option<&Ident>,
 ) -> ModuleItem {
     if let Some(runtime_module_ident) = runtime_module_ident {
         decl_var_and_assign_stmt(
-            &ident,
+            ident,
             obj_member_expr(runtime_module_ident.clone().into(), quote_ident!("default")),
         )
         .into()
@@ -115,7 +166,7 @@ pub fn create_default_import_stmt(
                 local: ident.clone(),
             }
             .into()],
-            src: Str::from(module_src.clone()).into(),
+            src: Str::from(module_src).into(),
             type_only: false,
             with: None,
             phase: ImportPhase::Evaluation,
@@ -128,14 +179,14 @@ pub fn create_default_import_stmt(
 /// eg. `const ident = {module_ident}.ident`
 /// eg. `import { ident } from "module_src"`
 pub fn create_named_import_stmt(
-    module_src: &String,
+    module_src: &str,
     ident: &Ident,
     runtime_module_ident: Option<&Ident>,
     imported: &Option<Ident>,
 ) -> ModuleItem {
     if let Some(runtime_module_ident) = runtime_module_ident {
         decl_var_and_assign_stmt(
-            &ident,
+            ident,
             obj_member_expr(
                 runtime_module_ident.clone().into(),
                 quote_ident!(imported.clone().unwrap_or(ident.clone()).sym),
@@ -148,13 +199,11 @@ pub fn create_named_import_stmt(
             specifiers: vec![ImportNamedSpecifier {
                 span: DUMMY_SP,
                 local: ident.clone(),
-                imported: imported.clone().and_then(|imported_ident| {
-                    Some(ModuleExportName::Ident(imported_ident.into()))
-                }),
+                imported: imported.clone().map(ModuleExportName::Ident),
                 is_type_only: false,
             }
             .into()],
-            src: Str::from(module_src.clone()).into(),
+            src: Str::from(module_src).into(),
             type_only: false,
             with: None,
             phase: ImportPhase::Evaluation,
@@ -168,23 +217,18 @@ pub fn create_named_import_stmt(
 /// eg. `const ident = global.__modules.helpers.asWildcard(module_ident)`
 /// eg. `import * as ident from "module_src"`
 pub fn create_namespace_import_stmt(
-    module_src: &String,
+    registry: &ModuleRegistry,
+    module_src: &str,
     ident: &Ident,
     runtime_module_ident: Option<&Ident>,
 ) -> ModuleItem {
     if let Some(runtime_module_ident) = runtime_module_ident {
         decl_var_and_assign_stmt(
-            &ident,
+            ident,
             Expr::Call(CallExpr {
                 span: DUMMY_SP,
                 type_args: None,
-                callee: Callee::Expr(Box::new(obj_member_expr(
-                    obj_member_expr(
-                        obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-                        quote_ident!(MODULE_HELPER_NAME),
-                    ),
-                    quote_ident!(HELPER_AS_WILDCARD_NAME),
-                ))),
+                callee: Callee::Expr(Box::new(registry.helper(HELPER_AS_WILDCARD_NAME))),
                 args: vec![runtime_module_ident.clone().as_arg()],
             }),
         )
@@ -192,7 +236,7 @@ pub fn create_namespace_import_stmt(
     } else {
         ModuleDecl::Import(ImportDecl {
             span: DUMMY_SP,
-            src: Str::from(module_src.clone()).into(),
+            src: Str::from(module_src).into(),
             type_only: false,
             with: None,
             phase: ImportPhase::Evaluation,
@@ -206,6 +250,42 @@ pub fn create_namespace_import_stmt(
     }
 }
 
+/// Returns the emitted code of the node.
+///
+/// Spans and syntax contexts are dropped before emitting
+/// so that the code is deterministic across runs and machines.
+///
+/// eg. `[count, setCount]`
+pub fn emit_code<N>(node: &N, minify: bool) -> String
+where
+    N: Node + Clone + VisitMutWith<DropSpan>,
+{
+    let mut node = node.clone();
+    node.visit_mut_with(&mut DropSpan {
+        preserve_ctxt: false,
+    });
+
+    let cm = Lrc::<SourceMap>::default();
+    let mut code = Vec::new();
+    let mut emitter = Emitter {
+        cfg: Config::default().with_minify(minify),
+        cm: cm.clone(),
+        comments: None,
+        wr: JsWriter::new(cm, "\n", &mut code, None),
+    };
+    node.emit_with(&mut emitter)
+        .expect("failed to emit the node");
+
+    String::from_utf8(code).expect("emitted code is not valid UTF-8")
+}
+
+/// Returns `true` if the name is component-like (capitalized).
+///
+/// eg. `App`, `Button`
+pub fn is_component_name(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_ascii_uppercase())
+}
+
 /// Check `ModuleDecl` is invalid.
 pub fn is_invalid_module_decl(module_decl: &ModuleDecl) -> bool {
     if let ModuleDecl::Import(ImportDecl {
@@ -0,0 +1,145 @@
+use swc_core::{
+    common::collections::AHashSet,
+    ecma::{
+        ast::*,
+        utils::{collect_decls, ExprFactory},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    constants::{HOT_ACCEPT_NAME, HOT_API_NAME},
+    registry::ModuleRegistry,
+    resolver::Resolver,
+};
+
+/// Rewrites hot module APIs into the hot context of the registry.
+///
+/// - `import.meta.hot` to `global.__modules.hot("module_id")`
+/// - `module.hot` to `global.__modules.hot("module_id")` (not a local `module` binding)
+///
+/// eg. `import.meta.hot.accept()` to `global.__modules.hot("module_id").accept()`
+///
+/// Dependencies of `hot.accept(deps, callback)` are resolved into module ids.
+pub struct HotModuleTransformer<'a> {
+    module_id: &'a str,
+    registry: &'a ModuleRegistry,
+    resolver: &'a dyn Resolver,
+    decls: AHashSet<Id>,
+    /// `true` if the module accepts its own updates (eg. `import.meta.hot.accept(callback)`).
+    pub self_accepting: bool,
+}
+
+impl<'a> HotModuleTransformer<'a> {
+    pub fn new(
+        module_id: &'a str,
+        registry: &'a ModuleRegistry,
+        resolver: &'a dyn Resolver,
+    ) -> Self {
+        HotModuleTransformer {
+            module_id,
+            registry,
+            resolver,
+            decls: Default::default(),
+            self_accepting: false,
+        }
+    }
+
+    /// Returns `true` if the expression is `import.meta.hot` or `module.hot`.
+    fn is_hot_api(&self, member_expr: &MemberExpr) -> bool {
+        let is_hot_prop = match &member_expr.prop {
+            MemberProp::Ident(ident) => ident.sym == HOT_API_NAME,
+            _ => false,
+        };
+
+        is_hot_prop
+            && match &*member_expr.obj {
+                Expr::MetaProp(MetaPropExpr {
+                    kind: MetaPropKind::ImportMeta,
+                    ..
+                }) => true,
+                Expr::Ident(ident) => ident.sym == "module" && !self.decls.contains(&ident.to_id()),
+                _ => false,
+            }
+    }
+
+    /// Returns `true` if the callee is `hot.accept` (eg. `import.meta.hot?.accept`).
+    fn is_hot_accept(&self, callee: &Expr) -> bool {
+        let member_expr = match callee {
+            Expr::Member(member_expr) => member_expr,
+            Expr::OptChain(OptChainExpr { base, .. }) => match &**base {
+                OptChainBase::Member(member_expr) => member_expr,
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        matches!(&member_expr.prop, MemberProp::Ident(prop) if prop.sym == HOT_ACCEPT_NAME)
+            && matches!(&*member_expr.obj, Expr::Member(hot_expr) if self.is_hot_api(hot_expr))
+    }
+
+    /// Marks the module as self accepting for `hot.accept()` and `hot.accept(callback)`,
+    /// or resolves the dependencies of `hot.accept(deps, callback)`.
+    fn visit_hot_accept_args(&mut self, args: &mut [ExprOrSpread]) {
+        match args.first_mut().map(|arg| &mut *arg.expr) {
+            None | Some(Expr::Fn(_) | Expr::Arrow(_)) => self.self_accepting = true,
+            Some(Expr::Lit(Lit::Str(src))) => self.resolve_dep(src),
+            Some(Expr::Array(ArrayLit { elems, .. })) => {
+                elems.iter_mut().flatten().for_each(|elem| {
+                    if let Expr::Lit(Lit::Str(src)) = &mut *elem.expr {
+                        self.resolve_dep(src);
+                    }
+                })
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces the dependency source with the resolved module id.
+    fn resolve_dep(&self, src: &mut Str) {
+        if let Some(module_id) = self.resolver.resolve(&src.value) {
+            *src = Str {
+                span: src.span,
+                value: module_id.into(),
+                raw: None,
+            };
+        }
+    }
+}
+
+impl VisitMut for HotModuleTransformer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        self.decls = collect_decls(&*module);
+        module.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        if let Callee::Expr(callee) = &call_expr.callee {
+            if self.is_hot_accept(callee) {
+                self.visit_hot_accept_args(&mut call_expr.args);
+            }
+        }
+        call_expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_opt_call(&mut self, opt_call: &mut OptCall) {
+        if self.is_hot_accept(&opt_call.callee) {
+            self.visit_hot_accept_args(&mut opt_call.args);
+        }
+        opt_call.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Member(member_expr) if self.is_hot_api(member_expr) => {
+                *expr = self
+                    .registry
+                    .api(HOT_API_NAME)
+                    .as_call(member_expr.span, vec![self.module_id.as_arg()]);
+            }
+            _ => expr.visit_mut_children_with(self),
+        }
+    }
+}
@@ -1,80 +1,252 @@
+mod boundary;
 mod cjs_transformer;
+mod config;
 mod constants;
+mod dependencies;
+mod error;
 mod esm_collector;
+mod externals;
+mod format;
+mod glob;
 mod helpers;
+mod hot_transformer;
+mod module_id;
 mod module_resolver;
+#[cfg(feature = "node")]
+mod node_resolver;
+mod path_resolver;
+mod pragma;
+mod react_refresh;
+mod registry;
+mod resolver;
+mod signature;
+mod version;
 
+use boundary::Boundary;
 use cjs_transformer::CommonJsTransformer;
-use constants::{ESM_API_NAME, GLOBAL, MODULE, MODULE_EXTERNAL_NAME};
+pub use config::{Config, ConfigOverride};
+use constants::{ESM_API_NAME, MODULE_EXTERNAL_NAME};
+use dependencies::{collect_dynamic_imports, Dependencies};
+pub use error::ConfigError;
 use esm_collector::{EsModuleCollector, ExportModule, ImportModule, ModuleType};
+pub use externals::{External, ExternalRule};
+pub use format::ModuleFormat;
+use format::{iife_stmt, DefineTransformer, MetroTransformer, SystemTransformer};
 use helpers::{
-    create_default_import_stmt, create_named_import_stmt, create_namespace_import_stmt,
-    decl_var_and_assign_stmt, external_module_from_global, import_module_from_global, obj_lit,
-    obj_member_expr,
+    as_module_expr, create_default_import_stmt, create_named_import_stmt,
+    create_namespace_import_stmt, decl_var_and_assign_stmt, external_module_from_global,
+    global_var_expr, import_module_from_global, obj_lit, require_call_expr, version_options_lit,
 };
-use module_resolver::ModuleResolver;
-use std::collections::HashMap;
+use hot_transformer::HotModuleTransformer;
+pub use module_id::{ModuleIdGenerator, ModuleIdStrategy};
+pub use module_resolver::{ModuleIdRule, ModuleIds, ModuleResolver};
+pub use path_resolver::ResolveOptions;
+use pragma::{has_keep_pragma, FilePragmas};
+use react_refresh::ReactRefresh;
+use regex::Regex;
+use registry::ModuleRegistry;
+use resolver::report_unresolved;
+pub use resolver::Resolver;
+pub use signature::{ExportChange, ExportKind, ModuleSignature, SignatureDiff};
+use std::collections::{BTreeMap, HashMap};
 use swc_core::{
-    common::DUMMY_SP,
+    common::{comments::Comments, errors::HANDLER, Span, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
         utils::{private_ident, quote_ident, ExprFactory},
         visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith},
     },
 };
+use version::{module_version, VersionInjector};
 
-pub struct GlobalModuleTransformer {
+pub struct GlobalModuleTransformer<C: Comments> {
     module_id: String,
     runtime_module: bool,
+    strict: bool,
+    react_refresh: bool,
+    version: bool,
+    format: ModuleFormat,
+    // `false` if the file is not matched by `include` or matched by `exclude`.
+    included: bool,
+    passthrough_pattern: Option<Regex>,
+    registry: ModuleRegistry,
     external_flags: HashMap<String, bool>,
-    resolver: ModuleResolver,
+    registered_modules: BTreeMap<String, RegisteredModule>,
+    resolver: Box<dyn Resolver>,
+    comments: Option<C>,
 }
 
-impl GlobalModuleTransformer {
+/// Imported module that is declared once per module id.
+struct RegisteredModule {
+    ident: Ident,
+    src: String,
+    external: Option<External>,
+}
+
+impl<C: Comments> GlobalModuleTransformer<C> {
     fn new(
-        module_id: String,
-        runtime_module: bool,
-        external_pattern: Option<String>,
-        module_ids: Option<HashMap<String, String>>,
-    ) -> Self {
-        GlobalModuleTransformer {
-            module_id,
-            runtime_module,
-            external_flags: Default::default(),
-            resolver: ModuleResolver::new(external_pattern, module_ids),
+        config: &Config,
+        filename: &str,
+        resolver: Box<dyn Resolver>,
+        comments: Option<C>,
+    ) -> Result<Self, ConfigError> {
+        if config.format != ModuleFormat::Register && !config.runtime_module {
+            return Err(ConfigError::new("format", "requires `runtimeModule`"));
+        }
+        // Passthrough statements are native imports that can't be placed in the wrappers.
+        if config.format != ModuleFormat::Register && config.passthrough_pattern.is_some() {
+            return Err(ConfigError::new(
+                "passthroughPattern",
+                "requires the `register` format",
+            ));
+        }
+
+        // SystemJS and Metro have no module metadata to register the version.
+        if matches!(config.format, ModuleFormat::System | ModuleFormat::Metro) && config.version {
+            return Err(ConfigError::new(
+                "version",
+                "not supported by the `system` and `metro` formats",
+            ));
+        }
+
+        // React Refresh registers the components with the hot context of the global module runtime.
+        if config.format == ModuleFormat::System && config.react_refresh {
+            return Err(ConfigError::new(
+                "reactRefresh",
+                "not supported by the `system` format",
+            ));
         }
+
+        Ok(GlobalModuleTransformer {
+            module_id: config.module_id(filename),
+            runtime_module: config.runtime_module,
+            strict: config.strict,
+            react_refresh: config.react_refresh,
+            version: config.version,
+            format: config.format,
+            included: config.is_included(filename)?,
+            passthrough_pattern: config
+                .passthrough_pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|error| ConfigError::new("passthroughPattern", error))?,
+            registry: ModuleRegistry::new(&config.global_object, &config.namespace)?,
+            external_flags: Default::default(),
+            registered_modules: Default::default(),
+            resolver,
+            comments,
+        })
+    }
+
+    /// Returns the pragmas in the leading comments of the module.
+    fn file_pragmas(&self, module: &Module) -> FilePragmas {
+        let pos = module
+            .body
+            .first()
+            .map_or(module.span.lo, |item| item.span().lo);
+        self.comments
+            .get_leading(pos)
+            .map(|comments| FilePragmas::parse(&comments))
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the item is kept as a native statement in runtime mode.
+    ///
+    /// - Import statements with the keep pragma (reported as errors in the other formats than `register`)
+    /// - Imports and re-exports of the sources matched by `passthroughPattern`
+    fn is_passthrough(&self, item: &ModuleItem) -> bool {
+        let src = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                if self
+                    .comments
+                    .get_leading(import_decl.span.lo)
+                    .is_some_and(|comments| has_keep_pragma(&comments))
+                {
+                    if self.format == ModuleFormat::Register {
+                        return true;
+                    }
+                    if HANDLER.is_set() {
+                        HANDLER.with(|handler| {
+                            handler
+                                .struct_span_err(
+                                    import_decl.span,
+                                    "keep pragma requires the `register` format",
+                                )
+                                .emit()
+                        });
+                    }
+                    return false;
+                }
+                &import_decl.src
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) => &export_all.src,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                src: Some(src), ..
+            })) => src,
+            _ => return false,
+        };
+
+        self.passthrough_pattern
+            .as_ref()
+            .is_some_and(|pattern| pattern.is_match(&src.value))
     }
 
-    fn register_external_module(&mut self, stmts: &mut Vec<ModuleItem>, src: &String) -> bool {
-        if !self.resolver.is_external(src) {
-            false
-        } else if let Some(_) = self.external_flags.get(src) {
+    /// Returns the identifier of the imported module (registered once per module id).
+    fn get_ident_by_src(&mut self, src: &str) -> &Ident {
+        let module_path = self.resolver.resolve(src).unwrap_or(src.to_string());
+        let resolver = &self.resolver;
+        &self
+            .registered_modules
+            .entry(module_path)
+            .or_insert_with(|| RegisteredModule {
+                ident: private_ident!(resolver.ident_name(src)),
+                src: src.to_string(),
+                external: resolver.external(src),
+            })
+            .ident
+    }
+
+    fn register_external_module(&mut self, stmts: &mut Vec<ModuleItem>, src: &str) -> bool {
+        let Some(External::Registry { key, version, .. }) = self.resolver.external(src) else {
+            // Modules provided by the global variable or `require` are not registered.
+            return false;
+        };
+
+        if self.external_flags.contains_key(src) {
             // Already registered.
             true
         } else {
-            self.external_flags.insert(src.clone(), true);
+            self.external_flags.insert(src.to_string(), true);
             let external_ident = private_ident!("__external");
 
             // import * as __external from 'src';
-            // global.__modules.external('src', __external);
-            stmts.push(create_namespace_import_stmt(src, &external_ident, None));
+            // global.__modules.external('key', __external, { version });
+            let mut args = vec![
+                Expr::from(key.as_str()).as_arg(),
+                external_ident.clone().as_arg(),
+            ];
+            if let Some(version) = version {
+                args.push(version_options_lit("version", &version).as_arg());
+            }
+            stmts.push(create_namespace_import_stmt(
+                &self.registry,
+                src,
+                &external_ident,
+                None,
+            ));
             stmts.push(
-                obj_member_expr(
-                    obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-                    quote_ident!(MODULE_EXTERNAL_NAME),
-                )
-                .as_call(
-                    DUMMY_SP,
-                    vec![Expr::from(src.as_str()).as_arg(), external_ident.as_arg()],
-                )
-                .into_stmt()
-                .into(),
+                self.registry
+                    .api(MODULE_EXTERNAL_NAME)
+                    .as_call(DUMMY_SP, args)
+                    .into_stmt()
+                    .into(),
             );
             true
         }
     }
 
-    fn convert_esm_import(&mut self, imports: &Vec<ImportModule>) -> Vec<ModuleItem> {
+    fn convert_esm_import(&mut self, imports: &[ImportModule]) -> Vec<ModuleItem> {
         let mut stmts = Vec::with_capacity(imports.len());
 
         imports.iter().for_each(
@@ -84,6 +256,7 @@ impl GlobalModuleTransformer {
                  module_src,
                  module_type,
                  as_export,
+                 ..
              }| {
                 if !self.runtime_module
                     && !*as_export
@@ -92,36 +265,31 @@ impl GlobalModuleTransformer {
                     return;
                 }
 
-                if self.runtime_module || (!self.runtime_module && *as_export) {
-                    let runtime_module_ident: Option<&Ident> =
-                        if self.runtime_module {
-                            Some(self.resolver.get_ident_by_src(
-                                module_src,
-                                self.resolver.is_external(module_src),
-                            ))
-                        } else {
-                            None
-                        };
-
-                    stmts.push(
-                        match module_type {
-                            ModuleType::Default | ModuleType::DefaultAsNamed => {
-                                create_default_import_stmt(module_src, ident, runtime_module_ident)
-                            }
-                            ModuleType::Named => create_named_import_stmt(
-                                module_src,
-                                ident,
-                                runtime_module_ident,
-                                imported,
-                            ),
-                            ModuleType::NamespaceOrAll => create_namespace_import_stmt(
-                                module_src,
-                                ident,
-                                runtime_module_ident,
-                            ),
+                if self.runtime_module || *as_export {
+                    let runtime_module_ident = if self.runtime_module {
+                        Some(self.get_ident_by_src(module_src).clone())
+                    } else {
+                        None
+                    };
+                    let runtime_module_ident = runtime_module_ident.as_ref();
+
+                    stmts.push(match module_type {
+                        ModuleType::Default | ModuleType::DefaultAsNamed => {
+                            create_default_import_stmt(module_src, ident, runtime_module_ident)
                         }
-                        .into(),
-                    )
+                        ModuleType::Named => create_named_import_stmt(
+                            module_src,
+                            ident,
+                            runtime_module_ident,
+                            imported,
+                        ),
+                        ModuleType::NamespaceOrAll => create_namespace_import_stmt(
+                            &self.registry,
+                            module_src,
+                            ident,
+                            runtime_module_ident,
+                        ),
+                    })
                 }
             },
         );
@@ -129,12 +297,40 @@ impl GlobalModuleTransformer {
         stmts
     }
 
-    fn convert_esm_export(&mut self, exports: &Vec<ExportModule>) -> Vec<ModuleItem> {
+    /// Reports unresolved sources of the collected imports (once per source literal).
+    ///
+    /// `sources` are the sources of the other imports (side effect and dynamic imports).
+    fn report_unresolved_imports(&self, imports: &[ImportModule], sources: &[Str]) {
+        imports
+            .iter()
+            .map(|import| (import.module_src_span, import.module_src.as_str()))
+            .chain(sources.iter().map(|src| (src.span, src.value.as_str())))
+            .collect::<BTreeMap<Span, &str>>()
+            .into_iter()
+            .for_each(|(span, src)| report_unresolved(self.resolver.as_ref(), src, span));
+    }
+
+    /// Returns resolved dependencies of the collected imports and the other import sources.
+    fn collect_dependencies(&self, imports: &[ImportModule], sources: &[Str]) -> Dependencies {
+        let mut dependencies = Dependencies::default();
+        imports
+            .iter()
+            .map(|import| import.module_src.as_str())
+            .chain(sources.iter().map(|src| src.value.as_str()))
+            .for_each(|src| dependencies.add(self.resolver.as_ref(), src));
+        dependencies
+    }
+
+    fn convert_esm_export(
+        &mut self,
+        exports: &[ExportModule],
+        dependencies: &Dependencies,
+    ) -> Vec<ModuleItem> {
         let mut stmts = Vec::with_capacity(exports.len());
         let mut export_props = Vec::new();
         let mut export_all_props = Vec::new();
 
-        exports.into_iter().for_each(
+        exports.iter().for_each(
             |ExportModule {
                  ident,
                  as_ident,
@@ -173,20 +369,19 @@ impl GlobalModuleTransformer {
             },
         );
 
-        if exports.len() > 0 {
+        if !exports.is_empty() {
             let mut args = vec![
                 self.module_id.as_str().as_arg(),
                 obj_lit(Some(export_props)).as_arg(),
+                obj_lit(Some(dependencies.as_meta_props())).as_arg(),
             ];
             args.extend(export_all_props);
             stmts.push(
-                obj_member_expr(
-                    obj_member_expr(quote_ident!(GLOBAL).into(), quote_ident!(MODULE).into()),
-                    quote_ident!(ESM_API_NAME),
-                )
-                .as_call(DUMMY_SP, args)
-                .into_stmt()
-                .into(),
+                self.registry
+                    .api(ESM_API_NAME)
+                    .as_call(DUMMY_SP, args)
+                    .into_stmt()
+                    .into(),
             );
         }
 
@@ -194,31 +389,93 @@ impl GlobalModuleTransformer {
     }
 }
 
-impl VisitMut for GlobalModuleTransformer {
+impl<C: Comments> VisitMut for GlobalModuleTransformer<C> {
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        if !self.included {
+            return;
+        }
+
+        let pragmas = self.file_pragmas(module);
+        if pragmas.ignore {
+            return;
+        }
+        if let Some(module_id) = pragmas.module_id {
+            self.module_id = module_id;
+        }
+
+        let mut hot_transformer =
+            HotModuleTransformer::new(&self.module_id, &self.registry, self.resolver.as_ref());
+        module.visit_mut_with(&mut hot_transformer);
+        let self_accepting = hot_transformer.self_accepting;
+
+        // Passthrough imports and re-exports are left as native statements in runtime mode.
+        let passthrough_items = if self.runtime_module {
+            let (passthrough_items, body) = std::mem::take(&mut module.body)
+                .into_iter()
+                .partition(|item| self.is_passthrough(item));
+            module.body = body;
+            passthrough_items
+        } else {
+            Vec::new()
+        };
+
+        let mut react_refresh = ReactRefresh::default();
+        if self.react_refresh {
+            react_refresh.instrument(&mut module.body);
+        }
+
+        // ES modules are evaluated in strict mode.
+        let es_module = module.body.iter().any(ModuleItem::is_module_decl);
         let mut esm_collector = EsModuleCollector::new(self.runtime_module);
 
         module.visit_mut_with(&mut esm_collector);
+        let mut sources = collect_dynamic_imports(module);
+        sources.extend(esm_collector.side_effect_imports.iter().cloned());
+        if self.strict {
+            self.report_unresolved_imports(&esm_collector.imports, &sources);
+        }
+        let mut dependencies = self.collect_dependencies(&esm_collector.imports, &sources);
+        if self.runtime_module {
+            // Side effect imports are evaluated by importing the modules.
+            esm_collector.side_effect_imports.iter().for_each(|src| {
+                self.get_ident_by_src(&src.value);
+            });
+        }
+        dependencies.boundary =
+            Boundary::classify(&module.body, &esm_collector.exports, self_accepting);
         module
             .body
             .splice(..0, self.convert_esm_import(&esm_collector.imports));
 
         module
             .body
-            .extend(self.convert_esm_export(&esm_collector.exports));
+            .extend(self.convert_esm_export(&esm_collector.exports, &dependencies));
 
         if self.runtime_module {
-            for (index, registered) in self.resolver.registered_idents.iter().enumerate() {
+            for (index, (module_path, registered)) in self.registered_modules.iter().enumerate() {
                 module.body.insert(
                     index,
                     decl_var_and_assign_stmt(
-                        registered.1,
-                        if self.resolver.is_external(registered.0) {
-                            external_module_from_global(registered.0)
-                        } else {
-                            import_module_from_global(registered.0)
+                        &registered.ident,
+                        match &registered.external {
+                            Some(External::Registry {
+                                key,
+                                required_version,
+                                ..
+                            }) => external_module_from_global(
+                                &self.registry,
+                                key,
+                                required_version.as_deref(),
+                            ),
+                            Some(External::Global(path)) => {
+                                as_module_expr(&self.registry, global_var_expr(path))
+                            }
+                            Some(External::Require) => {
+                                as_module_expr(&self.registry, require_call_expr(&registered.src))
+                            }
+                            None => import_module_from_global(&self.registry, module_path),
                         },
                     )
                     .into(),
@@ -226,26 +483,165 @@ impl VisitMut for GlobalModuleTransformer {
             }
         }
 
-        if esm_collector.exports.is_empty() {
-            module.visit_mut_with(&mut CommonJsTransformer::new(
-                &self.resolver,
+        let meta = if esm_collector.exports.is_empty() {
+            let mut cjs_transformer = CommonJsTransformer::new(
+                self.resolver.as_ref(),
                 self.module_id.clone(),
                 self.runtime_module,
-            ));
+                self.strict,
+                &self.registry,
+                dependencies,
+            );
+            module.visit_mut_with(&mut cjs_transformer);
+            cjs_transformer.meta()
+        } else {
+            obj_lit(Some(dependencies.as_meta_props()))
+        };
+
+        if !react_refresh.is_empty() {
+            // Signatures and default exports are declared after the import statements.
+            let index = module
+                .body
+                .iter()
+                .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+                .unwrap_or(module.body.len());
+            module.body.splice(index..index, react_refresh.decls());
+            module
+                .body
+                .extend(react_refresh.registration_stmts(&self.module_id, &self.registry));
+        }
+
+        match self.format {
+            ModuleFormat::Register => {}
+            ModuleFormat::Define => {
+                let mut define_transformer = DefineTransformer::new(&self.registry);
+                module.visit_mut_with(&mut define_transformer);
+                let stmts = take_stmts(module);
+                module.body.push(
+                    define_transformer
+                        .define_stmt(&self.module_id, meta, stmts)
+                        .into(),
+                );
+            }
+            ModuleFormat::System => {
+                let stmts = take_stmts(module);
+                module.body.push(
+                    SystemTransformer::new(&self.registry)
+                        .register_stmt(&self.module_id, stmts)
+                        .into(),
+                );
+            }
+            ModuleFormat::Metro => {
+                let stmts = take_stmts(module);
+                module.body.push(
+                    MetroTransformer::new(&self.registry)
+                        .define_stmt(&self.module_id, stmts)
+                        .into(),
+                );
+            }
+            ModuleFormat::Iife => {
+                let stmts = take_stmts(module);
+                module.body.push(iife_stmt(stmts, es_module).into());
+            }
+        }
+
+        module.body.splice(..0, passthrough_items);
+
+        if self.version {
+            let version = module_version(module);
+            module.visit_mut_with(&mut VersionInjector::new(&self.registry, version));
         }
     }
 }
 
-pub fn global_module(
-    module_id: String,
-    runtime_module: bool,
-    external_pattern: Option<String>,
-    module_ids: Option<HashMap<String, String>>,
-) -> impl VisitMut + Fold {
-    as_folder(GlobalModuleTransformer::new(
-        module_id,
-        runtime_module,
-        external_pattern,
-        module_ids,
-    ))
+/// Takes the statements of the module (module declarations are removed in runtime mode).
+fn take_stmts(module: &mut Module) -> Vec<Stmt> {
+    std::mem::take(&mut module.body)
+        .into_iter()
+        .filter_map(ModuleItem::stmt)
+        .collect()
+}
+
+/// Returns an error if the options are not valid (eg. invalid regular expressions).
+///
+/// `filename` is the file path of the module (used for the module id, resolving import sources and
+/// matching `include`, `exclude` and `overrides`). Files that are not included are returned untouched.
+///
+/// `comments` are used to read `@global-module` pragmas (pragmas are ignored if `None`).
+pub fn global_module<C: Comments>(
+    config: Config,
+    filename: String,
+    comments: Option<C>,
+) -> Result<impl VisitMut + Fold, ConfigError> {
+    let config = config.for_file(&filename)?;
+    let mut externals = config.externals.clone();
+    if let Some(external_pattern) = &config.external_pattern {
+        Regex::new(external_pattern).map_err(|error| ConfigError::new("externalPattern", error))?;
+        externals.push(ExternalRule::pattern(external_pattern));
+    }
+
+    let resolver = ModuleResolver::new(
+        externals,
+        config.module_ids.clone(),
+        &filename,
+        config.resolve.clone(),
+        config.module_id_generator(),
+    )?;
+
+    Ok(as_folder(GlobalModuleTransformer::new(
+        &config,
+        &filename,
+        Box::new(resolver),
+        comments,
+    )?))
+}
+
+/// Same as `global_module` but resolves import sources with the custom resolver.
+///
+/// Options of the default resolver (`externals`, `moduleIds` and `resolve`) are ignored.
+/// Files that are not included (`include` and `exclude`) are returned untouched.
+///
+/// Pass `ModuleResolver`s of a shared `ModuleIdGenerator` to detect module id collisions across files.
+pub fn global_module_with_resolver<C: Comments>(
+    config: Config,
+    filename: String,
+    resolver: impl Resolver + 'static,
+    comments: Option<C>,
+) -> Result<impl VisitMut + Fold, ConfigError> {
+    Ok(as_folder(GlobalModuleTransformer::new(
+        &config.for_file(&filename)?,
+        &filename,
+        Box::new(resolver),
+        comments,
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::comments::SingleThreadedComments;
+
+    use super::*;
+
+    #[test]
+    fn passthrough_pattern_with_format() {
+        let error = |format| {
+            let config = Config::default()
+                .with_runtime_module(true)
+                .with_passthrough_pattern("^core-js")
+                .with_format(format);
+            global_module::<SingleThreadedComments>(config, String::from("src/App.tsx"), None)
+                .err()
+                .map(|error| error.option().to_string())
+        };
+
+        assert_eq!(error(ModuleFormat::Register), None);
+        assert_eq!(
+            error(ModuleFormat::Define).as_deref(),
+            Some("passthroughPattern")
+        );
+        assert_eq!(
+            error(ModuleFormat::Iife).as_deref(),
+            Some("passthroughPattern")
+        );
+    }
 }
@@ -0,0 +1,268 @@
+use regex::{Captures, Regex};
+use serde::Deserialize;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use swc_core::common::{errors::HANDLER, sync::Lazy};
+
+use crate::path_resolver::normalize_path;
+
+/// Strategy to generate module ids from file paths.
+///
+/// - `"filename"`: File path as is (eg. `/Users/app/src/App.tsx`)
+/// - `"relative"`: Path relative to the root (eg. `src/App.tsx`)
+/// - `"hash"`: Hash of the relative path (eg. `5d41402abc4b2a76`)
+/// - `"numeric"`: Numeric hash of the relative path (eg. `1335831723`)
+/// - `{ "template": "[name]-[hash:8]" }`: Template with `[path]`, `[name]`, `[ext]`, `[hash]` and `[hash:<length>]` (eg. `App-5d41402a`)
+///
+/// `"numeric"` ids are 32-bit FNV-1a hashes, so collisions are likely in large projects
+/// (about 1% with 10,000 modules). Collisions are reported as errors (see [`ModuleIdGenerator`]).
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleIdStrategy {
+    #[default]
+    Filename,
+    Relative,
+    Hash,
+    Numeric,
+    Template(String),
+}
+
+/// Generates module ids of the file paths with the strategy.
+///
+/// Generated ids are kept to detect collisions of the hash strategies.
+/// Clones share the generated ids, so pass clones of one generator to `ModuleResolver::new`
+/// for all files (with `global_module_with_resolver`) to detect collisions across the project.
+#[derive(Clone, Debug)]
+pub struct ModuleIdGenerator {
+    strategy: ModuleIdStrategy,
+    root: String,
+    generated: Rc<RefCell<HashMap<String, String>>>,
+}
+
+impl ModuleIdGenerator {
+    /// `root` is the base directory of relative paths (eg. the project root).
+    pub fn new(strategy: ModuleIdStrategy, root: &str) -> Self {
+        ModuleIdGenerator {
+            strategy,
+            root: normalize_path(root),
+            generated: Default::default(),
+        }
+    }
+
+    /// Returns the module id of the file path.
+    ///
+    /// Emits an error diagnostic if the id is already generated for another path (requires `HANDLER`).
+    pub fn generate(&self, path: &str) -> String {
+        let relative_path = relative_path(&self.root, &normalize_path(path));
+        let module_id = match &self.strategy {
+            ModuleIdStrategy::Filename => path.to_string(),
+            ModuleIdStrategy::Relative => relative_path.clone(),
+            ModuleIdStrategy::Hash => format!("{:016x}", fnv1a_64(relative_path.as_bytes())),
+            ModuleIdStrategy::Numeric => fnv1a_32(relative_path.as_bytes()).to_string(),
+            ModuleIdStrategy::Template(template) => render_template(template, &relative_path),
+        };
+
+        if let Some(collided_path) = self.collided_path(&module_id, relative_path) {
+            if HANDLER.is_set() {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_err(&format!(
+                            "module id `{module_id}` of `{path}` collides with `{collided_path}`"
+                        ))
+                        .emit()
+                });
+            }
+        }
+
+        module_id
+    }
+
+    /// Records the module id of the path and returns the other path that has the same id.
+    fn collided_path(&self, module_id: &str, relative_path: String) -> Option<String> {
+        let mut generated = self.generated.borrow_mut();
+        match generated.get(module_id) {
+            Some(generated_path) if *generated_path != relative_path => {
+                Some(generated_path.clone())
+            }
+            Some(_) => None,
+            None => {
+                generated.insert(module_id.to_string(), relative_path);
+                None
+            }
+        }
+    }
+}
+
+/// Returns the path relative to the root with forward slashes.
+///
+/// eg. `/app/src/App.tsx` to `src/App.tsx` (root: `/app`)
+pub fn relative_path(root: &str, path: &str) -> String {
+    let root_segments = root.split('/').filter(|segment| !segment.is_empty());
+    let path_segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<&str>>();
+
+    let mut common_len = 0;
+    let mut parent_segments = Vec::new();
+    for segment in root_segments {
+        if parent_segments.is_empty() && path_segments.get(common_len) == Some(&segment) {
+            common_len += 1;
+        } else {
+            parent_segments.push("..");
+        }
+    }
+
+    parent_segments
+        .into_iter()
+        .chain(path_segments[common_len..].iter().copied())
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+static TEMPLATE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[(path|name|ext|hash)(?::(\d+))?\]").unwrap());
+
+fn render_template(template: &str, relative_path: &str) -> String {
+    let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+    let (name, ext) = match file_name.rfind('.') {
+        Some(index) if index > 0 => (&file_name[..index], &file_name[index + 1..]),
+        _ => (file_name, ""),
+    };
+    let hash = format!("{:016x}", fnv1a_64(relative_path.as_bytes()));
+
+    TEMPLATE_REGEX
+        .replace_all(template, |captures: &Captures| match &captures[1] {
+            "path" => relative_path.to_string(),
+            "name" => name.to_string(),
+            "ext" => ext.to_string(),
+            _ => {
+                let length = captures
+                    .get(2)
+                    .and_then(|length| length.as_str().parse::<usize>().ok())
+                    .unwrap_or(hash.len())
+                    .min(hash.len());
+                hash[..length].to_string()
+            }
+        })
+        .to_string()
+}
+
+/// 64-bit FNV-1a hash (stable across platforms and versions).
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// 32-bit FNV-1a hash.
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, byte| {
+        (hash ^ *byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(strategy: ModuleIdStrategy, path: &str) -> String {
+        ModuleIdGenerator::new(strategy, "/app").generate(path)
+    }
+
+    #[test]
+    fn relative_paths() {
+        assert_eq!(relative_path("/app", "/app/src/App.tsx"), "src/App.tsx");
+        assert_eq!(relative_path("/app/", "/app/src/App.tsx"), "src/App.tsx");
+        assert_eq!(relative_path("/app/src", "/app/lib/a.js"), "../lib/a.js");
+        assert_eq!(relative_path("/app", "/lib/a.js"), "../lib/a.js");
+        assert_eq!(relative_path("", "src/App.tsx"), "src/App.tsx");
+        assert_eq!(relative_path("app", "app/src/App.tsx"), "src/App.tsx");
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(fnv1a_64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a_32(b""), 0x811c9dc5);
+        assert_eq!(fnv1a_32(b"a"), 0xe40c292c);
+    }
+
+    #[test]
+    fn strategies() {
+        let path = "/app/src/App.tsx";
+        let hash = format!("{:016x}", fnv1a_64(b"src/App.tsx"));
+
+        assert_eq!(generate(ModuleIdStrategy::Filename, path), path);
+        assert_eq!(generate(ModuleIdStrategy::Relative, path), "src/App.tsx");
+        assert_eq!(
+            generate(ModuleIdStrategy::Relative, "\\app\\src\\App.tsx"),
+            "src/App.tsx"
+        );
+        assert_eq!(generate(ModuleIdStrategy::Hash, path), hash);
+        assert_eq!(
+            generate(ModuleIdStrategy::Numeric, path),
+            fnv1a_32(b"src/App.tsx").to_string()
+        );
+        assert_eq!(
+            generate(ModuleIdStrategy::Template("[name]-[hash:8]".into()), path),
+            format!("App-{}", &hash[..8])
+        );
+        assert_eq!(
+            generate(
+                ModuleIdStrategy::Template("[path]|[name].[ext]|[hash]".into()),
+                path
+            ),
+            format!("src/App.tsx|App.tsx|{hash}")
+        );
+    }
+
+    #[test]
+    fn collisions() {
+        let generator = ModuleIdGenerator::new(ModuleIdStrategy::Template("[name]".into()), "/app");
+
+        generator.generate("/app/src/a/index.ts");
+        generator.generate("/app/src/a/index.ts");
+        assert_eq!(
+            generator.collided_path("index", "src/a/index.ts".into()),
+            None
+        );
+        assert_eq!(
+            generator.collided_path("index", "src/b/index.ts".into()),
+            Some("src/a/index.ts".into())
+        );
+    }
+
+    #[test]
+    fn collisions_across_clones() {
+        let generator = ModuleIdGenerator::new(ModuleIdStrategy::Template("[name]".into()), "/app");
+
+        generator.clone().generate("/app/src/a/index.ts");
+        assert_eq!(
+            generator.collided_path("index", "src/b/index.ts".into()),
+            Some("src/a/index.ts".into())
+        );
+    }
+
+    #[test]
+    fn stable_across_roots() {
+        assert_eq!(
+            ModuleIdGenerator::new(ModuleIdStrategy::Hash, "/home/a/app")
+                .generate("/home/a/app/src/App.tsx"),
+            ModuleIdGenerator::new(ModuleIdStrategy::Hash, "C:\\Users\\b\\app")
+                .generate("C:\\Users\\b\\app\\src\\App.tsx"),
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert!(matches!(
+            serde_json::from_str::<ModuleIdStrategy>(r#""relative""#).unwrap(),
+            ModuleIdStrategy::Relative
+        ));
+        assert!(matches!(
+            serde_json::from_str::<ModuleIdStrategy>(r#"{ "template": "[hash:8]" }"#).unwrap(),
+            ModuleIdStrategy::Template(template) if template == "[hash:8]"
+        ));
+    }
+}
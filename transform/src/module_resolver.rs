@@ -1,58 +1,507 @@
 use regex::Regex;
-use std::collections::{BTreeMap, HashMap};
-use swc_core::ecma::{ast::*, utils::private_ident};
+use serde::Deserialize;
+use std::{cell::RefCell, collections::HashMap};
 
-pub type ModuleIds = HashMap<String, String>;
+use crate::{
+    error::ConfigError,
+    externals::{External, ExternalRule, ExternalRules},
+    module_id::ModuleIdGenerator,
+    path_resolver::{is_bare, PathResolver, ResolveOptions},
+    resolver::Resolver,
+};
 
+/// Actual module ids of the import sources.
+///
+/// - Map: `{ "<import source>": "<module id>" }`
+/// - Rules: `[{ "source": "react", "id": "12345" }, { "pattern": "@app/*", "id": "src/*" }]`
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ModuleIds {
+    Map(HashMap<String, String>),
+    Rules(Vec<ModuleIdRule>),
+}
+
+impl From<HashMap<String, String>> for ModuleIds {
+    fn from(module_ids: HashMap<String, String>) -> Self {
+        ModuleIds::Map(module_ids)
+    }
+}
+
+/// Rule that maps import sources to the module id.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged, deny_unknown_fields)]
+pub enum ModuleIdRule {
+    /// Exact import source.
+    ///
+    /// eg. `{ "source": "react", "id": "12345" }`
+    Exact { source: String, id: String },
+    /// Wildcard pattern. Each `*` in the `id` is substituted with the matched part.
+    ///
+    /// eg. `{ "pattern": "@app/components/*", "id": "src/components/*/index.tsx" }`
+    Wildcard { pattern: String, id: String },
+    /// Regular expression. Captures can be referenced in the `id` (eg. `$1`, `${name}`).
+    ///
+    /// eg. `{ "regex": "^@lib/(.+)$", "id": "lib/$1.js" }`
+    Regex { regex: String, id: String },
+}
+
+struct ModuleIdPattern {
+    regex: Regex,
+    id: String,
+}
+
+impl ModuleIdPattern {
+    /// Converts the wildcard pattern into the anchored regular expression.
+    ///
+    /// eg. `@app/*` to `^@app/(.*)$` with `src/*` to `src/${1}`
+    fn wildcard(pattern: &str, id: &str) -> Self {
+        let regex = pattern
+            .split('*')
+            .map(regex::escape)
+            .collect::<Vec<String>>()
+            .join("(.*)");
+        let id = id
+            .replace('$', "$$")
+            .split('*')
+            .enumerate()
+            .map(|(index, part)| {
+                if index == 0 {
+                    part.to_string()
+                } else {
+                    format!("${{{index}}}{part}")
+                }
+            })
+            .collect::<String>();
+
+        ModuleIdPattern {
+            regex: Regex::new(format!("^{regex}$").as_str()).unwrap(),
+            id,
+        }
+    }
+
+    fn regex(regex: &str, id: &str) -> Result<Self, regex::Error> {
+        Ok(ModuleIdPattern {
+            regex: Regex::new(regex)?,
+            id: id.to_string(),
+        })
+    }
+
+    fn resolve(&self, src: &str) -> Option<String> {
+        self.regex.captures(src).map(|captures| {
+            let mut module_id = String::new();
+            captures.expand(self.id.as_str(), &mut module_id);
+            module_id
+        })
+    }
+}
+
+/// Default resolver of the `externals`, `moduleIds` and `resolve` options.
 pub struct ModuleResolver {
-    pub registered_idents: BTreeMap<String, Ident>,
-    external_regex: Option<Regex>,
-    module_ids: Option<ModuleIds>,
-    normalize_regex: Regex,
+    external_rules: ExternalRules,
+    module_ids: HashMap<String, String>,
+    module_id_patterns: Vec<ModuleIdPattern>,
+    path_resolver: Option<PathResolver>,
+    module_id_generator: Option<ModuleIdGenerator>,
+    aliased: RefCell<HashMap<String, bool>>,
 }
 
 impl ModuleResolver {
-    pub fn new(external_pattern: Option<String>, module_ids: Option<ModuleIds>) -> Self {
-        ModuleResolver {
-            external_regex: external_pattern
-                .and_then(|pattern| Some(Regex::new(pattern.as_str()).unwrap())),
-            module_ids,
-            registered_idents: BTreeMap::new(),
-            normalize_regex: Regex::new(r"[^a-zA-Z0-9]").unwrap(),
+    /// Returns an error if the regular expression of the rules is not valid.
+    pub fn new(
+        externals: Vec<ExternalRule>,
+        module_ids: Option<ModuleIds>,
+        filename: &str,
+        resolve_options: Option<ResolveOptions>,
+        module_id_generator: Option<ModuleIdGenerator>,
+    ) -> Result<Self, ConfigError> {
+        let mut exact_module_ids = HashMap::new();
+        let mut module_id_patterns = Vec::new();
+
+        match module_ids {
+            Some(ModuleIds::Map(module_ids)) => exact_module_ids.extend(module_ids),
+            Some(ModuleIds::Rules(rules)) => {
+                for (index, rule) in rules.into_iter().enumerate() {
+                    match rule {
+                        ModuleIdRule::Exact { source, id } => {
+                            exact_module_ids.entry(source).or_insert(id);
+                        }
+                        ModuleIdRule::Wildcard { pattern, id } => {
+                            module_id_patterns.push(ModuleIdPattern::wildcard(&pattern, &id))
+                        }
+                        ModuleIdRule::Regex { regex, id } => module_id_patterns.push(
+                            ModuleIdPattern::regex(&regex, &id).map_err(|error| {
+                                ConfigError::new(format!("moduleIds[{index}].regex"), error)
+                            })?,
+                        ),
+                    }
+                }
+            }
+            None => {}
         }
-    }
 
-    pub fn get_ident_by_src(&mut self, src: &String, is_external: bool) -> &Ident {
-        let module_path = self
-            .to_actual_path(src, is_external)
-            .unwrap_or(src.to_string());
-        self.registered_idents
-            .entry(module_path)
-            .or_insert(private_ident!(self
-                .normalize_regex
-                .replace_all(format!("_{src}").as_str(), "_")
-                .to_string()))
+        Ok(ModuleResolver {
+            external_rules: ExternalRules::new(externals, filename)?,
+            module_ids: exact_module_ids,
+            module_id_patterns,
+            path_resolver: resolve_options.map(|options| PathResolver::new(filename, options)),
+            // Record the id of the importing file to detect collisions with the imported modules.
+            module_id_generator: module_id_generator.inspect(|module_id_generator| {
+                module_id_generator.generate(filename);
+            }),
+            aliased: Default::default(),
+        })
     }
 
-    pub fn to_actual_path(&self, src: &String, is_external: bool) -> Option<String> {
+    /// Returns the actual module id of the import source.
+    ///
+    /// Exact sources take precedence over patterns,
+    /// and patterns are matched in the declared order (first match wins).
+    /// Sources that are not matched are resolved into file paths
+    /// (relative sources against the importing file, others with `paths` and `baseUrl`)
+    /// and converted into module ids with the module id strategy.
+    pub fn to_actual_path(&self, src: &str, is_external: bool) -> Option<String> {
         if is_external {
             None
-        } else if let Some(actual_path) = self
-            .module_ids
-            .as_ref()
-            .and_then(|module_ids| module_ids.get(src))
+        } else if let Some(module_id) = self.module_ids.get(src) {
+            Some(module_id.clone())
+        } else if let Some(module_id) = self
+            .module_id_patterns
+            .iter()
+            .find_map(|pattern| pattern.resolve(src))
         {
-            Some(actual_path.clone())
+            Some(module_id)
         } else {
-            None
+            self.path_resolver.as_ref().and_then(|path_resolver| {
+                path_resolver
+                    .resolve_relative(src)
+                    .or_else(|| path_resolver.resolve_alias(src))
+                    .or_else(|| path_resolver.resolve_package(src))
+                    .map(|path| match &self.module_id_generator {
+                        Some(module_id_generator) => module_id_generator.generate(&path),
+                        None => path,
+                    })
+            })
         }
     }
 
-    pub fn is_external(&self, src: &String) -> bool {
-        if let Some(regex) = &self.external_regex {
-            regex.is_match(src.as_str())
-        } else {
-            false
+    /// Returns `true` if the source is mapped to the local module by `moduleIds` or `paths` and `baseUrl`.
+    ///
+    /// Results are cached by the source since `baseUrl` lookups access the filesystem.
+    fn is_aliased(&self, src: &str) -> bool {
+        if let Some(aliased) = self.aliased.borrow().get(src) {
+            return *aliased;
+        }
+
+        let aliased = self.module_ids.contains_key(src)
+            || self
+                .module_id_patterns
+                .iter()
+                .any(|pattern| pattern.regex.is_match(src))
+            || self
+                .path_resolver
+                .as_ref()
+                .is_some_and(|path_resolver| path_resolver.is_aliased(src));
+        self.aliased.borrow_mut().insert(src.to_string(), aliased);
+        aliased
+    }
+}
+
+impl Resolver for ModuleResolver {
+    fn resolve(&self, src: &str) -> Option<String> {
+        self.to_actual_path(src, self.is_external(src))
+    }
+
+    fn is_external(&self, src: &str) -> bool {
+        self.external(src).is_some()
+    }
+
+    /// Bare specifiers that are aliased to local modules are not matched by `bare` rules.
+    fn external(&self, src: &str) -> Option<External> {
+        self.external_rules
+            .external(src, is_bare(src) && !self.is_aliased(src))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module_id::ModuleIdStrategy;
+
+    fn exact(source: &str, id: &str) -> ModuleIdRule {
+        ModuleIdRule::Exact {
+            source: source.into(),
+            id: id.into(),
+        }
+    }
+
+    fn wildcard(pattern: &str, id: &str) -> ModuleIdRule {
+        ModuleIdRule::Wildcard {
+            pattern: pattern.into(),
+            id: id.into(),
+        }
+    }
+
+    fn regex(regex: &str, id: &str) -> ModuleIdRule {
+        ModuleIdRule::Regex {
+            regex: regex.into(),
+            id: id.into(),
         }
     }
+
+    fn resolver(rules: Vec<ModuleIdRule>) -> ModuleResolver {
+        ModuleResolver::new(vec![], Some(ModuleIds::Rules(rules)), "test.js", None, None).unwrap()
+    }
+
+    #[test]
+    fn invalid_regex() {
+        let error = ModuleResolver::new(
+            vec![],
+            Some(ModuleIds::Rules(vec![
+                wildcard("@app/*", "src/*"),
+                regex(r"^@lib/(.+$", "lib/$1.js"),
+            ])),
+            "test.js",
+            None,
+            None,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(error.option(), "moduleIds[1].regex");
+    }
+
+    #[test]
+    fn unknown_rule_fields() {
+        assert!(serde_json::from_str::<ModuleIdRule>(r#"{ "source": "a", "id": "b" }"#).is_ok());
+        assert!(serde_json::from_str::<ModuleIdRule>(
+            r#"{ "source": "a", "id": "b", "ids": "c" }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn exact_map() {
+        let resolver = ModuleResolver::new(
+            vec![],
+            Some(HashMap::from([("react".into(), "12345".into())]).into()),
+            "test.js",
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolver.to_actual_path("react", false),
+            Some("12345".into())
+        );
+        assert_eq!(resolver.to_actual_path("react-native", false), None);
+    }
+
+    #[test]
+    fn wildcard_substitution() {
+        let resolver = resolver(vec![wildcard(
+            "@app/components/*",
+            "src/components/*/index.tsx",
+        )]);
+
+        assert_eq!(
+            resolver.to_actual_path("@app/components/Button", false),
+            Some("src/components/Button/index.tsx".into())
+        );
+        assert_eq!(
+            resolver.to_actual_path("@app/components/forms/Input", false),
+            Some("src/components/forms/Input/index.tsx".into())
+        );
+        assert_eq!(resolver.to_actual_path("@app/hooks/useFoo", false), None);
+    }
+
+    #[test]
+    fn wildcard_multiple_captures() {
+        let resolver = resolver(vec![wildcard("@app/*/ui/*", "src/*/$ui/*.tsx")]);
+
+        assert_eq!(
+            resolver.to_actual_path("@app/home/ui/Header", false),
+            Some("src/home/$ui/Header.tsx".into())
+        );
+    }
+
+    #[test]
+    fn regex_substitution() {
+        let resolver = resolver(vec![
+            regex(r"^@lib/(.+)$", "lib/$1.js"),
+            regex(r"^@(?<scope>[a-z]+)/core$", "packages/${scope}/index.js"),
+        ]);
+
+        assert_eq!(
+            resolver.to_actual_path("@lib/utils/date", false),
+            Some("lib/utils/date.js".into())
+        );
+        assert_eq!(
+            resolver.to_actual_path("@shared/core", false),
+            Some("packages/shared/index.js".into())
+        );
+    }
+
+    #[test]
+    fn exact_takes_precedence_over_patterns() {
+        let resolver = resolver(vec![
+            wildcard("@app/*", "src/*"),
+            exact("@app/core", "core-module-id"),
+        ]);
+
+        assert_eq!(
+            resolver.to_actual_path("@app/core", false),
+            Some("core-module-id".into())
+        );
+        assert_eq!(
+            resolver.to_actual_path("@app/hooks", false),
+            Some("src/hooks".into())
+        );
+    }
+
+    #[test]
+    fn first_matching_pattern_wins() {
+        let resolver = resolver(vec![
+            wildcard("@app/components/*", "src/components/*/index.tsx"),
+            wildcard("@app/*", "src/*"),
+            regex(r"^@app/(.+)$", "unreachable/$1"),
+        ]);
+
+        assert_eq!(
+            resolver.to_actual_path("@app/components/Button", false),
+            Some("src/components/Button/index.tsx".into())
+        );
+        assert_eq!(
+            resolver.to_actual_path("@app/hooks", false),
+            Some("src/hooks".into())
+        );
+    }
+
+    #[test]
+    fn external_is_not_resolved() {
+        let resolver = ModuleResolver::new(
+            vec![ExternalRule::pattern("^react$")],
+            Some(ModuleIds::Rules(vec![wildcard("*", "vendor/*")])),
+            "test.js",
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(resolver.to_actual_path("react", true), None);
+        assert_eq!(
+            resolver.to_actual_path("lodash", false),
+            Some("vendor/lodash".into())
+        );
+    }
+
+    #[test]
+    fn module_ids_take_precedence_over_relative_resolution() {
+        let resolver = ModuleResolver::new(
+            vec![],
+            Some(ModuleIds::Rules(vec![exact(
+                "./Button",
+                "button-module-id",
+            )])),
+            "tests/fixture/esm/resolve/relative/utils/index.ts",
+            Some(ResolveOptions {
+                relative: true,
+                ..Default::default()
+            }),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolver.to_actual_path("./Button", false),
+            Some("button-module-id".into())
+        );
+        assert_eq!(
+            resolver.to_actual_path("../components/Button", false),
+            Some("tests/fixture/esm/resolve/relative/components/Button.tsx".into())
+        );
+        // Not exists.
+        assert_eq!(resolver.to_actual_path("../components/Input", false), None);
+    }
+
+    #[test]
+    fn module_id_strategy_applies_to_resolved_paths() {
+        let resolver = ModuleResolver::new(
+            vec![],
+            Some(ModuleIds::Rules(vec![exact("react", "12345")])),
+            "tests/fixture/esm/resolve/relative/utils/index.ts",
+            Some(ResolveOptions {
+                relative: true,
+                ..Default::default()
+            }),
+            Some(ModuleIdGenerator::new(
+                ModuleIdStrategy::Relative,
+                "tests/fixture/esm/resolve",
+            )),
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolver.to_actual_path("../components/Button", false),
+            Some("relative/components/Button.tsx".into())
+        );
+        // Explicit module ids are not converted.
+        assert_eq!(
+            resolver.to_actual_path("react", false),
+            Some("12345".into())
+        );
+        assert_eq!(resolver.to_actual_path("lodash", false), None);
+    }
+
+    #[test]
+    fn bare_rule_skips_aliased_sources() {
+        let resolver = ModuleResolver::new(
+            vec![ExternalRule {
+                bare: true,
+                ..Default::default()
+            }],
+            Some(ModuleIds::Rules(vec![wildcard("@app/*", "src/*")])),
+            "src/a/index.ts",
+            Some(ResolveOptions {
+                relative: true,
+                paths: HashMap::from([("~/*".into(), vec!["src/*".into()])]),
+                ..Default::default()
+            }),
+            None,
+        )
+        .unwrap();
+
+        assert!(resolver.is_external("react"));
+        assert!(!resolver.is_external("@app/Button"));
+        assert!(!resolver.is_external("~/Button"));
+        assert!(!resolver.is_external("./Button"));
+        // Aliased but the target does not exist.
+        assert_eq!(resolver.to_actual_path("~/Button", false), None);
+    }
+
+    #[test]
+    fn deserialize_rules() {
+        let module_ids = serde_json::from_str::<ModuleIds>(
+            r#"[
+                { "source": "react", "id": "12345" },
+                { "pattern": "@app/*", "id": "src/*" },
+                { "regex": "^@lib/(.+)$", "id": "lib/$1" }
+            ]"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            module_ids,
+            ModuleIds::Rules(rules) if matches!(
+                rules.as_slice(),
+                [
+                    ModuleIdRule::Exact { .. },
+                    ModuleIdRule::Wildcard { .. },
+                    ModuleIdRule::Regex { .. },
+                ]
+            )
+        ));
+    }
 }
@@ -0,0 +1,366 @@
+use serde_json::{Map, Value};
+use std::{fs, path::Path};
+
+use crate::path_resolver::{is_bare, join_path, match_wildcard, normalize_path, PathResolver};
+
+impl PathResolver {
+    /// Returns the file path of the package import source.
+    ///
+    /// - Bare sources: eg. `lib/sub` to `node_modules/lib/dist/sub.js` (package.json `exports` or main fields)
+    /// - Subpath imports: eg. `#internal` to `src/internal.js` (`imports` of the nearest package.json)
+    pub fn resolve_package(&self, src: &str) -> Option<String> {
+        if !self.options().node_modules {
+            return None;
+        }
+
+        if src.starts_with('#') {
+            self.resolve_imports(src)
+        } else if is_bare(src) {
+            self.resolve_node_modules(self.importer_dir(), src)
+        } else {
+            None
+        }
+    }
+
+    /// Resolves the subpath import with `imports` of the nearest package.json.
+    fn resolve_imports(&self, src: &str) -> Option<String> {
+        let (package_dir, package_json) = find_package_json(self.importer_dir())?;
+        let target = self.resolve_subpath(package_json.get("imports")?.as_object()?, src)?;
+
+        if target.starts_with("./") {
+            Some(self.resolve_target_file(&package_dir, &target))
+        } else {
+            // Mapped to the external package (eg. `"#dep": "lib"`).
+            self.resolve_node_modules(&package_dir, &target)
+        }
+    }
+
+    /// Looks up the package from `node_modules` of the directory and its ancestors.
+    fn resolve_node_modules(&self, from_dir: &str, src: &str) -> Option<String> {
+        let (package_name, subpath) = split_package_name(src)?;
+        self.resolve_package_entry(&find_package_dir(from_dir, package_name)?, &subpath)
+    }
+
+    /// Resolves the subpath (eg. `.`, `./sub`) of the package.
+    fn resolve_package_entry(&self, package_dir: &str, subpath: &str) -> Option<String> {
+        let package_json = read_json(&join_path(package_dir, "package.json"));
+
+        if let Some(exports) = package_json.as_ref().and_then(|json| json.get("exports")) {
+            return self
+                .resolve_exports(exports, subpath)
+                .map(|target| self.resolve_target_file(package_dir, &target));
+        }
+
+        if subpath != "." {
+            return self.resolve_file(&normalize_path(&join_path(package_dir, subpath)));
+        }
+
+        package_json
+            .iter()
+            .flat_map(|json| {
+                self.options()
+                    .main_fields
+                    .iter()
+                    .filter_map(|field| json.get(field)?.as_str())
+            })
+            .find_map(|main| self.resolve_file(&normalize_path(&join_path(package_dir, main))))
+            .or_else(|| self.resolve_file(package_dir))
+    }
+
+    /// Returns the target of the subpath in package.json `exports`.
+    fn resolve_exports(&self, exports: &Value, subpath: &str) -> Option<String> {
+        match exports.as_object() {
+            Some(subpaths) if subpaths.keys().any(|key| key.starts_with('.')) => {
+                self.resolve_subpath(subpaths, subpath)
+            }
+            // Shorthand of the main entry (eg. `"exports": "./index.js"`).
+            _ if subpath == "." => self.resolve_target(exports, ""),
+            _ => None,
+        }
+    }
+
+    /// Returns the target of the matched key.
+    ///
+    /// Exact keys take precedence over patterns, and the pattern with the longest prefix wins.
+    fn resolve_subpath(&self, subpaths: &Map<String, Value>, key: &str) -> Option<String> {
+        if let Some(target) = subpaths.get(key).filter(|_| !key.contains('*')) {
+            return self.resolve_target(target, "");
+        }
+
+        subpaths
+            .iter()
+            .filter_map(|(pattern, target)| {
+                let (prefix_len, matched) = match_wildcard(pattern, key)?;
+                Some((prefix_len, target, matched))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .and_then(|(_, target, matched)| self.resolve_target(target, matched))
+    }
+
+    /// Returns the first target matched with the conditions.
+    ///
+    /// `*` of the target is substituted with the matched part.
+    fn resolve_target(&self, target: &Value, matched: &str) -> Option<String> {
+        match target {
+            Value::String(target) => Some(target.replace('*', matched)),
+            Value::Array(targets) => targets
+                .iter()
+                .find_map(|target| self.resolve_target(target, matched)),
+            Value::Object(conditions) => conditions
+                .iter()
+                .filter(|(condition, _)| {
+                    *condition == "default" || self.options().conditions.contains(condition)
+                })
+                .find_map(|(_, target)| self.resolve_target(target, matched)),
+            _ => None,
+        }
+    }
+
+    fn resolve_target_file(&self, package_dir: &str, target: &str) -> String {
+        let path = normalize_path(&join_path(package_dir, target));
+        self.resolve_file(&path).unwrap_or(path)
+    }
+}
+
+/// Splits the source into the package name and the subpath.
+///
+/// eg. `@scope/lib/sub` to `@scope/lib` and `./sub`
+fn split_package_name(src: &str) -> Option<(&str, String)> {
+    let name_len = if src.starts_with('@') {
+        let scope_len = src.find('/')?;
+        src[scope_len + 1..]
+            .find('/')
+            .map_or(src.len(), |index| scope_len + 1 + index)
+    } else {
+        src.find('/').unwrap_or(src.len())
+    };
+
+    Some((&src[..name_len], format!(".{}", &src[name_len..])))
+}
+
+/// Returns the `version` of the installed package of the source.
+///
+/// eg. `18.2.0` for `react-dom/client`
+pub fn installed_version(from_dir: &str, src: &str) -> Option<String> {
+    let (package_name, _) = split_package_name(src)?;
+    let package_json = read_json(&join_path(
+        &find_package_dir(from_dir, package_name)?,
+        "package.json",
+    ))?;
+
+    Some(package_json.get("version")?.as_str()?.to_string())
+}
+
+/// Returns the version range of the source in dependencies of the nearest package.json.
+///
+/// eg. `^18.0.0` for `react-dom/client`
+pub fn required_version(from_dir: &str, src: &str) -> Option<String> {
+    let (package_name, _) = split_package_name(src)?;
+    let (_, package_json) = find_package_json(from_dir)?;
+
+    [
+        "dependencies",
+        "peerDependencies",
+        "optionalDependencies",
+        "devDependencies",
+    ]
+    .iter()
+    .find_map(|field| {
+        Some(
+            package_json
+                .get(field)?
+                .get(package_name)?
+                .as_str()?
+                .to_string(),
+        )
+    })
+}
+
+/// Returns the directory of the package in `node_modules` of the directory and its ancestors.
+fn find_package_dir(from_dir: &str, package_name: &str) -> Option<String> {
+    let mut dir = Some(from_dir);
+
+    while let Some(current_dir) = dir {
+        let package_dir = join_path(&join_path(current_dir, "node_modules"), package_name);
+        if Path::new(&package_dir).is_dir() {
+            return Some(package_dir);
+        }
+        dir = parent_dir(current_dir);
+    }
+
+    None
+}
+
+/// Returns the directory and the contents of the nearest package.json.
+fn find_package_json(from_dir: &str) -> Option<(String, Value)> {
+    let mut dir = Some(from_dir);
+
+    while let Some(current_dir) = dir {
+        if let Some(package_json) = read_json(&join_path(current_dir, "package.json")) {
+            return Some((current_dir.to_string(), package_json));
+        }
+        dir = parent_dir(current_dir);
+    }
+
+    None
+}
+
+fn parent_dir(dir: &str) -> Option<&str> {
+    match dir.rfind('/') {
+        Some(0) if dir.len() > 1 => Some("/"),
+        Some(0) => None,
+        Some(index) => Some(&dir[..index]),
+        None if dir.is_empty() => None,
+        None => Some(""),
+    }
+}
+
+fn read_json(path: &str) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path_resolver::ResolveOptions;
+
+    const PACKAGES_DIR: &str = "tests/fixture/esm/resolve/packages";
+
+    fn resolver(conditions: &[&str]) -> PathResolver {
+        PathResolver::new(
+            &format!("{PACKAGES_DIR}/src/index.js"),
+            ResolveOptions {
+                node_modules: true,
+                conditions: conditions.iter().map(|c| c.to_string()).collect(),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn package_path(path: &str) -> Option<String> {
+        Some(format!("{PACKAGES_DIR}/{path}"))
+    }
+
+    #[test]
+    fn package_names() {
+        assert_eq!(split_package_name("lib"), Some(("lib", ".".into())));
+        assert_eq!(split_package_name("lib/a/b"), Some(("lib", "./a/b".into())));
+        assert_eq!(
+            split_package_name("@scope/lib"),
+            Some(("@scope/lib", ".".into()))
+        );
+        assert_eq!(
+            split_package_name("@scope/lib/a"),
+            Some(("@scope/lib", "./a".into()))
+        );
+        assert_eq!(split_package_name("@scope"), None);
+    }
+
+    #[test]
+    fn parent_dirs() {
+        assert_eq!(parent_dir("src/a"), Some("src"));
+        assert_eq!(parent_dir("src"), Some(""));
+        assert_eq!(parent_dir(""), None);
+        assert_eq!(parent_dir("/src"), Some("/"));
+        assert_eq!(parent_dir("/"), None);
+    }
+
+    #[test]
+    fn resolve_exports_with_conditions() {
+        assert_eq!(
+            resolver(&["react-native", "import"]).resolve_package("lib"),
+            package_path("node_modules/lib/dist/index.native.js")
+        );
+        assert_eq!(
+            resolver(&["import"]).resolve_package("lib"),
+            package_path("node_modules/lib/dist/index.mjs")
+        );
+        assert_eq!(
+            resolver(&[]).resolve_package("lib"),
+            package_path("node_modules/lib/dist/index.js")
+        );
+    }
+
+    #[test]
+    fn resolve_exports_subpaths() {
+        let resolver = resolver(&[]);
+
+        assert_eq!(
+            resolver.resolve_package("lib/sub"),
+            package_path("node_modules/lib/dist/sub.js")
+        );
+        assert_eq!(
+            resolver.resolve_package("lib/features/a"),
+            package_path("node_modules/lib/dist/features/a.js")
+        );
+        // Not exported.
+        assert_eq!(resolver.resolve_package("lib/dist/sub.js"), None);
+    }
+
+    #[test]
+    fn resolve_main_fields() {
+        let resolver = resolver(&[]);
+
+        assert_eq!(
+            resolver.resolve_package("legacy"),
+            package_path("node_modules/legacy/es/main.js")
+        );
+        assert_eq!(
+            resolver.resolve_package("legacy/lib/util"),
+            package_path("node_modules/legacy/lib/util.js")
+        );
+        assert_eq!(
+            resolver.resolve_package("@scope/pkg"),
+            package_path("node_modules/@scope/pkg/index.js")
+        );
+        assert_eq!(resolver.resolve_package("missing"), None);
+    }
+
+    #[test]
+    fn resolve_imports() {
+        assert_eq!(
+            resolver(&[]).resolve_package("#internal/format"),
+            package_path("src/internal/format.js")
+        );
+        assert_eq!(
+            resolver(&["react-native"]).resolve_package("#platform"),
+            package_path("src/platform.native.js")
+        );
+        assert_eq!(
+            resolver(&[]).resolve_package("#platform"),
+            package_path("src/platform.js")
+        );
+        assert_eq!(
+            resolver(&[]).resolve_package("#lib"),
+            package_path("node_modules/lib/dist/index.js")
+        );
+    }
+
+    #[test]
+    fn package_versions() {
+        let from_dir = format!("{PACKAGES_DIR}/src");
+
+        assert_eq!(
+            installed_version(&from_dir, "lib/sub"),
+            Some("1.2.3".into())
+        );
+        assert_eq!(installed_version(&from_dir, "legacy"), Some("0.1.0".into()));
+        assert_eq!(installed_version(&from_dir, "missing"), None);
+        assert_eq!(
+            required_version(&from_dir, "lib/sub"),
+            Some("^1.0.0".into())
+        );
+        assert_eq!(required_version(&from_dir, "legacy"), Some("~0.1.0".into()));
+        assert_eq!(required_version(&from_dir, "missing"), None);
+    }
+
+    #[test]
+    fn disabled() {
+        let resolver = PathResolver::new(
+            &format!("{PACKAGES_DIR}/src/index.js"),
+            ResolveOptions::default(),
+        );
+
+        assert_eq!(resolver.resolve_package("lib"), None);
+    }
+}
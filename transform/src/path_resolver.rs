@@ -0,0 +1,607 @@
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Options for resolving import sources into file paths.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ResolveOptions {
+    /// Resolve relative import sources against the importing file.
+    ///
+    /// Requires filesystem access to find the files with `extensions` and `indexFiles`
+    /// (native, or the Wasm plugin with the files under the mapped working directory).
+    /// Sources of the files that do not exist are not resolved.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub relative: bool,
+    /// Extensions to try in order.
+    ///
+    /// Defaults to `[".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs", ".json"]`.
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+    /// File names to try in order when the source is a directory.
+    ///
+    /// Defaults to `["index"]`.
+    #[serde(default = "default_index_files")]
+    pub index_files: Vec<String>,
+    /// Base directory to resolve non-relative sources (tsconfig `compilerOptions.baseUrl`).
+    ///
+    /// Defaults to none.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Path aliases (tsconfig `compilerOptions.paths`).
+    ///
+    /// Targets are resolved against the `baseUrl`.
+    ///
+    /// eg. `{ "@app/*": ["src/*"] }`
+    #[serde(default)]
+    pub paths: HashMap<String, Vec<String>>,
+    /// Resolve bare sources from `node_modules` and `#` sources from package.json `imports`.
+    ///
+    /// Requires the `node` feature (filesystem access, native only).
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub node_modules: bool,
+    /// Condition names to match in package.json `exports` and `imports` (`default` is always matched).
+    ///
+    /// Conditions are matched in the order of the package.json keys.
+    ///
+    /// Defaults to `["import", "require"]`.
+    #[serde(default = "default_conditions")]
+    pub conditions: Vec<String>,
+    /// package.json fields to try in order when the package has no `exports`.
+    ///
+    /// Defaults to `["module", "main"]`.
+    #[serde(default = "default_main_fields")]
+    pub main_fields: Vec<String>,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        ResolveOptions {
+            relative: false,
+            extensions: default_extensions(),
+            index_files: default_index_files(),
+            base_url: None,
+            paths: HashMap::new(),
+            node_modules: false,
+            conditions: default_conditions(),
+            main_fields: default_main_fields(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfig {
+    #[serde(default)]
+    compiler_options: TsConfigCompilerOptions,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TsConfigCompilerOptions {
+    base_url: Option<String>,
+    #[serde(default)]
+    paths: HashMap<String, Vec<String>>,
+}
+
+impl ResolveOptions {
+    /// Reads `baseUrl` and `paths` from the tsconfig file.
+    ///
+    /// `baseUrl` is resolved against the directory of the tsconfig file
+    /// (the directory itself if `baseUrl` is not specified but `paths` is).
+    pub fn with_tsconfig(mut self, tsconfig_path: impl AsRef<Path>) -> io::Result<Self> {
+        let tsconfig_path = tsconfig_path.as_ref();
+        let tsconfig = serde_json::from_str::<TsConfig>(&strip_json_comments(&fs::read_to_string(
+            tsconfig_path,
+        )?))
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        let compiler_options = tsconfig.compiler_options;
+        let tsconfig_dir = tsconfig_path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_default();
+
+        if compiler_options.base_url.is_some() || !compiler_options.paths.is_empty() {
+            self.base_url = Some(normalize_path(
+                tsconfig_dir
+                    .join(compiler_options.base_url.unwrap_or_default())
+                    .to_string_lossy()
+                    .as_ref(),
+            ));
+        }
+        self.paths = compiler_options.paths;
+
+        Ok(self)
+    }
+}
+
+fn default_extensions() -> Vec<String> {
+    [".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs", ".json"]
+        .map(String::from)
+        .to_vec()
+}
+
+fn default_index_files() -> Vec<String> {
+    vec![String::from("index")]
+}
+
+fn default_conditions() -> Vec<String> {
+    vec![String::from("import"), String::from("require")]
+}
+
+fn default_main_fields() -> Vec<String> {
+    vec![String::from("module"), String::from("main")]
+}
+
+pub struct PathResolver {
+    importer_dir: String,
+    options: ResolveOptions,
+}
+
+impl PathResolver {
+    pub fn new(filename: &str, options: ResolveOptions) -> Self {
+        PathResolver {
+            importer_dir: dirname(filename),
+            options,
+        }
+    }
+
+    #[cfg(feature = "node")]
+    pub fn importer_dir(&self) -> &str {
+        &self.importer_dir
+    }
+
+    #[cfg(feature = "node")]
+    pub fn options(&self) -> &ResolveOptions {
+        &self.options
+    }
+
+    /// Returns the normalized file path of the relative import source.
+    ///
+    /// eg. `./Button` in `src/app/index.ts` to `src/app/Button.tsx`
+    ///
+    /// Returns `None` if the file does not exist (the source is used as the module id as is).
+    pub fn resolve_relative(&self, src: &str) -> Option<String> {
+        if !self.options.relative || !is_relative(src) {
+            return None;
+        }
+
+        let path = normalize_path(
+            if self.importer_dir.is_empty() {
+                src.to_string()
+            } else {
+                format!("{}/{src}", self.importer_dir)
+            }
+            .as_str(),
+        );
+
+        self.resolve_file(&path)
+    }
+
+    /// Returns `true` if the source is matched by `paths` or exists under the `baseUrl`.
+    ///
+    /// `paths` are matched without accessing the filesystem.
+    pub fn is_aliased(&self, src: &str) -> bool {
+        if is_relative(src) || src.starts_with('/') {
+            false
+        } else if self.match_paths(src).is_some() {
+            true
+        } else {
+            self.resolve_alias(src).is_some()
+        }
+    }
+
+    /// Returns the file path of the aliased import source.
+    ///
+    /// - Sources matched by `paths`: eg. `@app/Button` with `{ "@app/*": ["src/*"] }` to `src/Button.tsx`
+    /// - Sources that exist under the `baseUrl`: eg. `utils/date` to `src/utils/date.ts` (`baseUrl: "src"`)
+    ///
+    /// Returns `None` if none of the matched `paths` targets exists.
+    pub fn resolve_alias(&self, src: &str) -> Option<String> {
+        if is_relative(src) || src.starts_with('/') {
+            return None;
+        }
+
+        let base_url = self.options.base_url.as_deref().unwrap_or_default();
+
+        if let Some((targets, matched)) = self.match_paths(src) {
+            let paths = targets
+                .iter()
+                .map(|target| {
+                    normalize_path(&join_path(base_url, &target.replacen('*', matched, 1)))
+                })
+                .collect::<Vec<String>>();
+
+            return paths.iter().find_map(|path| self.resolve_file(path));
+        }
+
+        self.options
+            .base_url
+            .as_ref()
+            .and_then(|base_url| self.resolve_file(&normalize_path(&join_path(base_url, src))))
+    }
+
+    /// Returns targets of the matched `paths` entry and the part matched by `*`.
+    ///
+    /// Exact keys take precedence over patterns, and the pattern with the longest prefix wins.
+    fn match_paths<'a>(&'a self, src: &'a str) -> Option<(&'a Vec<String>, &'a str)> {
+        if let Some(targets) = self.options.paths.get(src).filter(|_| !src.contains('*')) {
+            return Some((targets, ""));
+        }
+
+        self.options
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                let (prefix_len, matched) = match_wildcard(pattern, src)?;
+                Some((prefix_len, targets, matched))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .map(|(_, targets, matched)| (targets, matched))
+    }
+
+    /// Returns the file path of the package import source.
+    ///
+    /// Always `None` without the `node` feature.
+    #[cfg(not(feature = "node"))]
+    pub fn resolve_package(&self, _src: &str) -> Option<String> {
+        None
+    }
+
+    /// Returns the first existing file among the path itself,
+    /// the path with extensions and the index files of the path.
+    pub fn resolve_file(&self, path: &str) -> Option<String> {
+        if Path::new(path).is_file() {
+            return Some(path.to_string());
+        }
+
+        let extensions = &self.options.extensions;
+        let with_extensions = extensions.iter().map(|ext| format!("{path}{ext}"));
+        let index_files = self.options.index_files.iter().flat_map(|index_file| {
+            extensions
+                .iter()
+                .map(move |ext| join_path(path, format!("{index_file}{ext}").as_str()))
+        });
+
+        with_extensions
+            .chain(index_files)
+            .find(|candidate| Path::new(candidate).is_file())
+    }
+}
+
+/// Returns `true` if the source is relative to the importing file.
+pub fn is_relative(src: &str) -> bool {
+    src == "." || src == ".." || src.starts_with("./") || src.starts_with("../")
+}
+
+/// Returns `true` if the source is a package name (eg. `react`, `@scope/lib/sub`).
+pub fn is_bare(src: &str) -> bool {
+    !src.is_empty()
+        && !is_relative(src)
+        && !src.starts_with('/')
+        && !src.starts_with('#')
+        && !src.contains(':')
+}
+
+/// Returns the length of the prefix and the part matched by `*` of the pattern.
+///
+/// eg. `lib` for `@app/lib/index` with `@app/*/index`
+pub fn match_wildcard<'a>(pattern: &str, src: &'a str) -> Option<(usize, &'a str)> {
+    let (prefix, suffix) = pattern.split_once('*')?;
+    if src.len() >= prefix.len() + suffix.len() && src.starts_with(prefix) && src.ends_with(suffix)
+    {
+        Some((prefix.len(), &src[prefix.len()..src.len() - suffix.len()]))
+    } else {
+        None
+    }
+}
+
+/// Removes comments and trailing commas of the JSON with comments (eg. tsconfig).
+fn strip_json_comments(json: &str) -> String {
+    let mut stripped = String::with_capacity(json.len());
+    let mut chars = json.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(char) = chars.next() {
+        if in_string {
+            stripped.push(char);
+            match char {
+                '\\' => stripped.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (char, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                stripped.push(char);
+            }
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        stripped.push(next);
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = None;
+                for next in chars.by_ref() {
+                    if prev == Some('*') && next == '/' {
+                        break;
+                    }
+                    prev = Some(next);
+                }
+            }
+            ('}' | ']', _) => {
+                // Remove the trailing comma.
+                let trimmed_len = stripped.trim_end().len();
+                if stripped[..trimmed_len].ends_with(',') {
+                    stripped.remove(trimmed_len - 1);
+                }
+                stripped.push(char);
+            }
+            _ => stripped.push(char),
+        }
+    }
+
+    stripped
+}
+
+pub fn join_path(base: &str, path: &str) -> String {
+    if base.is_empty() || base.ends_with('/') {
+        format!("{base}{path}")
+    } else {
+        format!("{base}/{path}")
+    }
+}
+
+/// Returns the normalized directory of the file path.
+///
+/// eg. `src/a/index.ts` to `src/a`
+pub fn dirname(path: &str) -> String {
+    let path = normalize_path(path);
+    match path.rfind('/') {
+        Some(index) => path[..index].to_string(),
+        None => String::new(),
+    }
+}
+
+/// Normalizes the path with forward slashes and without `.` and `..` segments.
+///
+/// eg. `src\a\..\b/./c` to `src/b/c`
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let is_absolute = path.starts_with('/');
+    let mut segments: Vec<&str> = Vec::new();
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => match segments.last() {
+                Some(&last) if last != ".." => {
+                    segments.pop();
+                }
+                _ if is_absolute => {}
+                _ => segments.push(segment),
+            },
+            _ => segments.push(segment),
+        }
+    }
+
+    let normalized = segments.join("/");
+    if is_absolute {
+        format!("/{normalized}")
+    } else {
+        normalized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver(filename: &str) -> PathResolver {
+        PathResolver::new(
+            filename,
+            ResolveOptions {
+                relative: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_path("src/a/../b/./c"), "src/b/c");
+        assert_eq!(normalize_path("src\\a\\index.ts"), "src/a/index.ts");
+        assert_eq!(normalize_path("/root/../../src//a"), "/src/a");
+        assert_eq!(normalize_path("../../src/a"), "../../src/a");
+        assert_eq!(normalize_path("./src/a/"), "src/a");
+    }
+
+    #[test]
+    fn relative_sources() {
+        assert!(is_relative("./Button"));
+        assert!(is_relative("../Button"));
+        assert!(is_relative("."));
+        assert!(is_relative(".."));
+        assert!(!is_relative("react"));
+        assert!(!is_relative(".prettierrc"));
+        assert!(!is_relative("/src/Button"));
+    }
+
+    #[test]
+    fn bare_sources() {
+        assert!(is_bare("react"));
+        assert!(is_bare("@scope/lib/sub"));
+        assert!(!is_bare("./Button"));
+        assert!(!is_bare("/src/Button"));
+        assert!(!is_bare("#internal"));
+        assert!(!is_bare("node:fs"));
+    }
+
+    #[test]
+    fn resolve_against_importer() {
+        assert_eq!(
+            resolver("tests/fixture/esm/resolve/relative/components/Button.tsx")
+                .resolve_relative("../utils"),
+            Some("tests/fixture/esm/resolve/relative/utils/index.ts".into())
+        );
+        assert_eq!(
+            resolver("tests/fixture/esm/resolve/relative/utils/index.ts")
+                .resolve_relative("../components/./Button"),
+            Some("tests/fixture/esm/resolve/relative/components/Button.tsx".into())
+        );
+        assert_eq!(
+            resolver("tests/fixture/esm/resolve/relative/../relative/input.js")
+                .resolve_relative("./utils"),
+            Some("tests/fixture/esm/resolve/relative/utils/index.ts".into())
+        );
+        // Not exists.
+        assert_eq!(
+            resolver("src/a/index.ts").resolve_relative("./Button"),
+            None
+        );
+        assert_eq!(resolver("src/a/index.ts").resolve_relative("react"), None);
+    }
+
+    #[test]
+    fn resolve_existing_files() {
+        let resolver = resolver("tests/fixture/esm/resolve/relative/input.js");
+
+        assert_eq!(
+            resolver.resolve_relative("./components/Button"),
+            Some("tests/fixture/esm/resolve/relative/components/Button.tsx".into())
+        );
+        assert_eq!(
+            resolver.resolve_relative("./utils"),
+            Some("tests/fixture/esm/resolve/relative/utils/index.ts".into())
+        );
+        assert_eq!(
+            resolver.resolve_relative("./components/Button.tsx"),
+            Some("tests/fixture/esm/resolve/relative/components/Button.tsx".into())
+        );
+    }
+
+    fn alias_resolver(base_url: Option<&str>, paths: &[(&str, &[&str])]) -> PathResolver {
+        PathResolver::new(
+            "src/a/index.ts",
+            ResolveOptions {
+                base_url: base_url.map(String::from),
+                paths: paths
+                    .iter()
+                    .map(|(pattern, targets)| {
+                        (
+                            pattern.to_string(),
+                            targets.iter().map(|target| target.to_string()).collect(),
+                        )
+                    })
+                    .collect(),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn resolve_paths() {
+        let resolver = alias_resolver(
+            Some("./app"),
+            &[
+                ("@app/*", &["src/*"]),
+                ("@app/components/*", &["src/ui/*", "src/components/*"]),
+                ("@config", &["config/index.ts"]),
+            ],
+        );
+
+        // Targets do not exist.
+        assert_eq!(resolver.resolve_alias("@app/hooks/useFoo"), None);
+        assert_eq!(resolver.resolve_alias("@app/components/Button"), None);
+        assert_eq!(resolver.resolve_alias("@config"), None);
+        assert_eq!(resolver.resolve_alias("react"), None);
+        assert_eq!(resolver.resolve_alias("./Button"), None);
+        assert!(resolver.is_aliased("@app/hooks/useFoo"));
+        assert!(!resolver.is_aliased("react"));
+        assert!(!resolver.is_aliased("./Button"));
+    }
+
+    #[test]
+    fn resolve_paths_with_existing_files() {
+        let resolver = alias_resolver(
+            Some("tests/fixture/esm/resolve"),
+            &[("@app/*", &["missing/*", "relative/*"])],
+        );
+
+        assert_eq!(
+            resolver.resolve_alias("@app/components/Button"),
+            Some("tests/fixture/esm/resolve/relative/components/Button.tsx".into())
+        );
+        assert_eq!(
+            resolver.resolve_alias("@app/utils"),
+            Some("tests/fixture/esm/resolve/relative/utils/index.ts".into())
+        );
+    }
+
+    #[test]
+    fn resolve_base_url() {
+        let resolver = alias_resolver(Some("tests/fixture/esm/resolve/relative"), &[]);
+
+        assert_eq!(
+            resolver.resolve_alias("utils"),
+            Some("tests/fixture/esm/resolve/relative/utils/index.ts".into())
+        );
+        // Not exists under the `baseUrl`.
+        assert_eq!(resolver.resolve_alias("react"), None);
+    }
+
+    #[test]
+    fn read_tsconfig() {
+        let options = ResolveOptions::default()
+            .with_tsconfig("tests/fixture/esm/resolve/tsconfig/tsconfig.json")
+            .unwrap();
+
+        assert_eq!(
+            options.base_url,
+            Some("tests/fixture/esm/resolve/tsconfig/src".into())
+        );
+        assert_eq!(
+            options.paths.get("@app/*"),
+            Some(&vec![String::from("./*")])
+        );
+    }
+
+    #[test]
+    fn strip_comments() {
+        assert_eq!(
+            strip_json_comments(
+                r#"{
+                    // comment
+                    "a": "// not a comment", /* comment */
+                    "b": ["/*", "*/",],
+                }"#
+            )
+            .split_whitespace()
+            .collect::<String>(),
+            r#"{"a":"//notacomment","b":["/*","*/"]}"#
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        let resolver = PathResolver::new("src/a/index.ts", ResolveOptions::default());
+
+        assert_eq!(resolver.resolve_relative("./Button"), None);
+    }
+}
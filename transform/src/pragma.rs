@@ -0,0 +1,131 @@
+use swc_core::common::comments::Comment;
+
+const PRAGMA: &str = "@global-module";
+const IGNORE_PRAGMA: &str = "@global-module-ignore";
+const KEEP_PRAGMA: &str = "@global-module-keep";
+
+/// Pragmas in the leading comments of the file.
+///
+/// - `/* @global-module id="app/root" */`: Module id of the file
+/// - `/* @global-module-ignore */`: Skip the file
+#[derive(Debug, Default, PartialEq)]
+pub struct FilePragmas {
+    pub module_id: Option<String>,
+    pub ignore: bool,
+}
+
+impl FilePragmas {
+    pub fn parse(comments: &[Comment]) -> Self {
+        let mut pragmas = FilePragmas::default();
+
+        comments.iter().for_each(|comment| {
+            if has_pragma(&comment.text, IGNORE_PRAGMA) {
+                pragmas.ignore = true;
+            }
+
+            let mut rest = comment.text.as_str();
+            while let Some(attrs) = next_pragma(&mut rest, PRAGMA) {
+                if let Some(id) = attr_value(attrs, "id") {
+                    pragmas.module_id = Some(id.to_string());
+                }
+            }
+        });
+
+        pragmas
+    }
+}
+
+/// Returns `true` if the leading comments of the import statement have the keep pragma.
+///
+/// eg. `/* @global-module-keep */ import 'polyfill';`
+pub fn has_keep_pragma(comments: &[Comment]) -> bool {
+    comments
+        .iter()
+        .any(|comment| has_pragma(&comment.text, KEEP_PRAGMA))
+}
+
+fn has_pragma(text: &str, name: &str) -> bool {
+    let mut rest = text;
+    next_pragma(&mut rest, name).is_some()
+}
+
+/// Finds the next pragma `name` (not followed by other name characters like `-ignore`)
+/// and returns the text after it.
+fn next_pragma<'a>(rest: &mut &'a str, name: &str) -> Option<&'a str> {
+    while let Some(index) = rest.find(name) {
+        let after = &rest[index + name.len()..];
+        *rest = after;
+        if !after.starts_with(|char: char| char.is_alphanumeric() || char == '-') {
+            return Some(after);
+        }
+    }
+    None
+}
+
+/// Returns the value of the attribute (eg. `id="app/root"`).
+fn attr_value<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let attrs = attrs.trim_start();
+    let value = attrs.strip_prefix(name)?.strip_prefix('=')?;
+    let quote = value
+        .chars()
+        .next()
+        .filter(|char| *char == '"' || *char == '\'')?;
+    let value = &value[1..];
+    value.find(quote).map(|end| &value[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::{comments::CommentKind, DUMMY_SP};
+
+    use super::*;
+
+    fn comments(texts: &[&str]) -> Vec<Comment> {
+        texts
+            .iter()
+            .map(|text| Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: (*text).into(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn file_pragmas() {
+        assert_eq!(
+            FilePragmas::parse(&comments(&[" @global-module id=\"app/root\" "])),
+            FilePragmas {
+                module_id: Some(String::from("app/root")),
+                ignore: false,
+            }
+        );
+        assert_eq!(
+            FilePragmas::parse(&comments(&[
+                "*\n * Root.\n * @global-module id='app/root'\n "
+            ])),
+            FilePragmas {
+                module_id: Some(String::from("app/root")),
+                ignore: false,
+            }
+        );
+        assert_eq!(
+            FilePragmas::parse(&comments(&["eslint-disable", " @global-module-ignore "])),
+            FilePragmas {
+                module_id: None,
+                ignore: true,
+            }
+        );
+        assert_eq!(
+            FilePragmas::parse(&comments(&[" @global-module-keep ", " @global-module id="])),
+            FilePragmas::default()
+        );
+    }
+
+    #[test]
+    fn keep_pragma() {
+        assert!(has_keep_pragma(&comments(&[" @global-module-keep "])));
+        assert!(!has_keep_pragma(&comments(&[" @global-module-keeps "])));
+        assert!(!has_keep_pragma(&comments(&[" @global-module-ignore "])));
+    }
+}
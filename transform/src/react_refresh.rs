@@ -0,0 +1,569 @@
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::*,
+        codegen::Node,
+        utils::{private_ident, quote_ident, DropSpan, ExprFactory},
+        visit::{noop_visit_type, Visit, VisitMutWith, VisitWith},
+    },
+};
+
+use crate::{
+    constants::{
+        HOT_ACCEPT_NAME, HOT_API_NAME, HOT_DATA_NAME, HOT_DISPOSE_NAME, HOT_INVALIDATE_NAME,
+        IS_REFRESH_BOUNDARY_API_NAME, REFRESH_DATA_NAME, REFRESH_DEFAULT_NAME, REFRESH_REG_NAME,
+        REFRESH_SIG_NAME,
+    },
+    helpers::{decl_var_and_assign_stmt, emit_code, is_component_name, obj_member_expr},
+    registry::ModuleRegistry,
+};
+
+const BUILTIN_HOOKS: &[&str] = &[
+    "use",
+    "useActionState",
+    "useCallback",
+    "useContext",
+    "useDebugValue",
+    "useDeferredValue",
+    "useEffect",
+    "useFormStatus",
+    "useId",
+    "useImperativeHandle",
+    "useInsertionEffect",
+    "useLayoutEffect",
+    "useMemo",
+    "useOptimistic",
+    "useReducer",
+    "useRef",
+    "useState",
+    "useSyncExternalStore",
+    "useTransition",
+];
+
+/// Hook signature of the component.
+struct Signature {
+    ident: Ident,
+    key: String,
+    custom_hooks: Vec<Expr>,
+}
+
+/// Component-like declaration (function or class with a capitalized name).
+struct Component {
+    ident: Ident,
+    /// Registered name (the declared name, or `%default%` for the default export).
+    name: String,
+    signature: Option<Signature>,
+}
+
+/// Instruments components for React Fast Refresh.
+///
+/// Registers the top-level components with the module id and signs the hooks of them.
+/// Default exports of higher-order components are registered as `%default%`.
+///
+/// ```js
+/// const _s = $RefreshSig$();
+/// function App() {
+///   _s();
+///   const [count, setCount] = useState(0);
+///   ...
+/// }
+/// _s(App, "useState{[count, setCount](0)}");
+/// $RefreshReg$(App, "module_id App");
+///
+/// if (global.__modules.isRefreshBoundary("module_id")) {
+///   global.__modules.hot("module_id").accept();
+/// } else if (global.__modules.hot("module_id").data.__refreshed) {
+///   // Re-executed by the hot update (not the first evaluation).
+///   global.__modules.hot("module_id").invalidate();
+/// }
+/// global.__modules.hot("module_id").dispose((data) => {
+///   data.__refreshed = true;
+/// });
+/// ```
+#[derive(Default)]
+pub struct ReactRefresh {
+    components: Vec<Component>,
+    /// Identifiers assigned with the default exports (eg. `export default _c = memo(App)`).
+    default_idents: Vec<Ident>,
+}
+
+impl ReactRefresh {
+    /// Collects the top-level components and signs the hooks of them.
+    pub fn instrument(&mut self, items: &mut [ModuleItem]) {
+        items.iter_mut().for_each(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                self.instrument_decl(decl)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export_default_decl)) => {
+                match &mut export_default_decl.decl {
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident),
+                        function,
+                    }) => self.instrument_fn(ident, function),
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident), ..
+                    }) => self.register(ident, None),
+                    _ => {}
+                }
+            }
+            // `export default memo(App)` to `export default _c = memo(App)`
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) if matches!(&**expr, Expr::Call(call_expr) if is_hoc_call(call_expr)) => {
+                let ident = private_ident!("_c");
+                **expr = expr
+                    .take()
+                    .make_assign_to(AssignOp::Assign, ident.clone().into());
+                self.components.push(Component {
+                    ident: ident.clone(),
+                    name: REFRESH_DEFAULT_NAME.to_string(),
+                    signature: None,
+                });
+                self.default_idents.push(ident);
+            }
+            _ => {}
+        });
+    }
+
+    /// Returns `true` if any components are registered.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns the declarations of the hook signatures and the default exports.
+    ///
+    /// eg. `const _s = $RefreshSig$()`, `var _c`
+    pub fn decls(&self) -> Vec<ModuleItem> {
+        let mut decls = self
+            .signatures()
+            .map(|signature| {
+                decl_var_and_assign_stmt(
+                    &signature.ident,
+                    quote_ident!(REFRESH_SIG_NAME).as_call(DUMMY_SP, vec![]),
+                )
+                .into()
+            })
+            .collect::<Vec<ModuleItem>>();
+        if !self.default_idents.is_empty() {
+            decls.push(
+                Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: self
+                        .default_idents
+                        .iter()
+                        .map(|ident| VarDeclarator {
+                            span: DUMMY_SP,
+                            name: ident.clone().into(),
+                            init: None,
+                            definite: false,
+                        })
+                        .collect(),
+                })))
+                .into(),
+            );
+        }
+        decls
+    }
+
+    /// Returns the statements that sign and register the components
+    /// and check the refresh boundary of the module.
+    pub fn registration_stmts(
+        &self,
+        module_id: &str,
+        registry: &ModuleRegistry,
+    ) -> Vec<ModuleItem> {
+        let mut stmts = Vec::new();
+
+        // _s(App, "useState{}", false, () => [useCustomHook]);
+        for component in &self.components {
+            let Some(signature) = &component.signature else {
+                continue;
+            };
+            let mut args = vec![
+                component.ident.clone().as_arg(),
+                Expr::from(signature.key.as_str()).as_arg(),
+            ];
+            if !signature.custom_hooks.is_empty() {
+                args.push(Expr::from(false).as_arg());
+                args.push(
+                    ArrowExpr {
+                        span: DUMMY_SP,
+                        params: vec![],
+                        is_async: false,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                        body: Box::new(BlockStmtOrExpr::Expr(Box::new(
+                            ArrayLit {
+                                span: DUMMY_SP,
+                                elems: signature
+                                    .custom_hooks
+                                    .iter()
+                                    .map(|hook| Some(hook.clone().as_arg()))
+                                    .collect(),
+                            }
+                            .into(),
+                        ))),
+                    }
+                    .as_arg(),
+                );
+            }
+            stmts.push(
+                signature
+                    .ident
+                    .clone()
+                    .as_call(DUMMY_SP, args)
+                    .into_stmt()
+                    .into(),
+            );
+        }
+
+        // $RefreshReg$(App, "module_id App");
+        for component in &self.components {
+            stmts.push(
+                quote_ident!(REFRESH_REG_NAME)
+                    .as_call(
+                        DUMMY_SP,
+                        vec![
+                            component.ident.clone().as_arg(),
+                            Expr::from(format!("{} {}", module_id, component.name)).as_arg(),
+                        ],
+                    )
+                    .into_stmt()
+                    .into(),
+            );
+        }
+
+        let hot_api = |name: &str| {
+            obj_member_expr(
+                registry
+                    .api(HOT_API_NAME)
+                    .as_call(DUMMY_SP, vec![module_id.as_arg()]),
+                quote_ident!(name),
+            )
+        };
+        // `hot.data.__refreshed`
+        let refreshed_expr =
+            || obj_member_expr(hot_api(HOT_DATA_NAME), quote_ident!(REFRESH_DATA_NAME));
+        stmts.push(
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: registry
+                    .api(IS_REFRESH_BOUNDARY_API_NAME)
+                    .as_call(DUMMY_SP, vec![module_id.as_arg()])
+                    .into(),
+                cons: Box::new(block_stmt(
+                    hot_api(HOT_ACCEPT_NAME)
+                        .as_call(DUMMY_SP, vec![])
+                        .into_stmt(),
+                )),
+                // Invalidate only when the module is re-executed by the hot update.
+                alt: Some(Box::new(Stmt::If(IfStmt {
+                    span: DUMMY_SP,
+                    test: refreshed_expr().into(),
+                    cons: Box::new(block_stmt(
+                        hot_api(HOT_INVALIDATE_NAME)
+                            .as_call(DUMMY_SP, vec![])
+                            .into_stmt(),
+                    )),
+                    alt: None,
+                }))),
+            })
+            .into(),
+        );
+
+        // `hot.dispose((data) => { data.__refreshed = true; })`
+        let data_ident = private_ident!("data");
+        stmts.push(
+            hot_api(HOT_DISPOSE_NAME)
+                .as_call(
+                    DUMMY_SP,
+                    vec![ArrowExpr {
+                        span: DUMMY_SP,
+                        params: vec![data_ident.clone().into()],
+                        is_async: false,
+                        is_generator: false,
+                        type_params: None,
+                        return_type: None,
+                        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts: vec![Expr::from(true)
+                                .make_assign_to(
+                                    AssignOp::Assign,
+                                    MemberExpr {
+                                        span: DUMMY_SP,
+                                        obj: data_ident.into(),
+                                        prop: quote_ident!(REFRESH_DATA_NAME).into(),
+                                    }
+                                    .into(),
+                                )
+                                .into_stmt()],
+                        })),
+                    }
+                    .as_arg()],
+                )
+                .into_stmt()
+                .into(),
+        );
+
+        stmts
+    }
+
+    fn signatures(&self) -> impl Iterator<Item = &Signature> {
+        self.components
+            .iter()
+            .filter_map(|component| component.signature.as_ref())
+    }
+
+    fn register(&mut self, ident: &Ident, signature: Option<Signature>) {
+        if is_component_name(&ident.sym) {
+            self.components.push(Component {
+                ident: ident.clone(),
+                name: ident.sym.to_string(),
+                signature,
+            });
+        }
+    }
+
+    fn instrument_decl(&mut self, decl: &mut Decl) {
+        match decl {
+            Decl::Fn(FnDecl {
+                ident, function, ..
+            }) => self.instrument_fn(ident, function),
+            Decl::Class(ClassDecl { ident, .. }) => self.register(ident, None),
+            Decl::Var(var_decl) => var_decl.decls.iter_mut().for_each(|var_declarator| {
+                let (Pat::Ident(BindingIdent { id, .. }), Some(init)) =
+                    (&var_declarator.name, &mut var_declarator.init)
+                else {
+                    return;
+                };
+                if !is_component_name(&id.sym) {
+                    return;
+                }
+
+                match &mut **init {
+                    Expr::Fn(FnExpr { function, .. }) => {
+                        let signature = function.body.as_mut().and_then(sign_block);
+                        self.register(id, signature);
+                    }
+                    Expr::Arrow(ArrowExpr { body, .. }) => {
+                        let signature = sign_arrow_body(body);
+                        self.register(id, signature);
+                    }
+                    // Higher-order components (eg. `memo(...)`, `forwardRef(...)`).
+                    Expr::Call(call_expr) if is_hoc_call(call_expr) => self.register(id, None),
+                    _ => {}
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    fn instrument_fn(&mut self, ident: &Ident, function: &mut Function) {
+        if is_component_name(&ident.sym) {
+            let signature = function.body.as_mut().and_then(sign_block);
+            self.register(ident, signature);
+        }
+    }
+}
+
+/// Returns `true` if the call is a higher-order component call
+/// (eg. `memo(() => ...)`, `forwardRef(function Button() {})`, `connect(mapState)(App)`).
+///
+/// Same as react-refresh/babel: the first argument is a function, a component-like identifier
+/// or another higher-order component call (`require` and `import` calls are excluded).
+pub fn is_hoc_call(call_expr: &CallExpr) -> bool {
+    let Callee::Expr(callee) = &call_expr.callee else {
+        return false;
+    };
+    if matches!(&**callee, Expr::Ident(ident) if ident.sym.starts_with("require") || ident.sym.starts_with("import"))
+    {
+        return false;
+    }
+
+    call_expr.args.first().is_some_and(|arg| match &*arg.expr {
+        Expr::Fn(_) | Expr::Arrow(_) => true,
+        Expr::Ident(ident) => is_component_name(&ident.sym),
+        Expr::Call(call_expr) => is_hoc_call(call_expr),
+        _ => false,
+    })
+}
+
+fn is_hook_name(name: &str) -> bool {
+    name == "use"
+        || name
+            .strip_prefix("use")
+            .is_some_and(|rest| rest.starts_with(|char: char| char.is_ascii_uppercase()))
+}
+
+fn block_stmt(stmt: Stmt) -> Stmt {
+    Stmt::Block(BlockStmt {
+        span: DUMMY_SP,
+        stmts: vec![stmt],
+    })
+}
+
+/// Inserts `_s()` to the function body if it calls hooks.
+fn sign_block(body: &mut BlockStmt) -> Option<Signature> {
+    let mut hook_collector = HookCollector::default();
+    body.visit_with(&mut hook_collector);
+
+    let signature = hook_collector.into_signature()?;
+    body.stmts.insert(
+        0,
+        signature
+            .ident
+            .clone()
+            .as_call(DUMMY_SP, vec![])
+            .into_stmt(),
+    );
+    Some(signature)
+}
+
+fn sign_arrow_body(body: &mut BlockStmtOrExpr) -> Option<Signature> {
+    match body {
+        BlockStmtOrExpr::BlockStmt(block_stmt) => sign_block(block_stmt),
+        BlockStmtOrExpr::Expr(expr) => {
+            // `() => expr` to `() => { return expr; }`
+            let mut block_stmt = BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![Stmt::Return(ReturnStmt {
+                    span: DUMMY_SP,
+                    arg: Some(expr.clone()),
+                })],
+            };
+            let signature = sign_block(&mut block_stmt)?;
+            *body = BlockStmtOrExpr::BlockStmt(block_stmt);
+            Some(signature)
+        }
+    }
+}
+
+/// Hook call of the component.
+struct Hook {
+    name: String,
+    /// Same as react-refresh/babel: the declared pattern and the initial state
+    /// (eg. `[count, setCount](0)` for `const [count, setCount] = useState(0)`).
+    key: String,
+    callee: Expr,
+}
+
+/// Collects hook calls of the component (except nested functions).
+#[derive(Default)]
+struct HookCollector {
+    hooks: Vec<Hook>,
+}
+
+impl HookCollector {
+    fn into_signature(self) -> Option<Signature> {
+        if self.hooks.is_empty() {
+            return None;
+        }
+
+        let key = self
+            .hooks
+            .iter()
+            .map(|hook| format!("{}{{{}}}", hook.name, hook.key))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let custom_hooks = self
+            .hooks
+            .into_iter()
+            .filter(|hook| !BUILTIN_HOOKS.contains(&hook.name.as_str()))
+            .map(|hook| hook.callee)
+            .collect();
+
+        Some(Signature {
+            ident: private_ident!("_s"),
+            key,
+            custom_hooks,
+        })
+    }
+
+    /// Collects the hook call with the declared pattern (eg. `const [count, setCount] = useState(0)`).
+    fn collect(&mut self, call_expr: &CallExpr, pat: Option<&Pat>) {
+        let Callee::Expr(callee) = &call_expr.callee else {
+            return;
+        };
+        let name = match &**callee {
+            Expr::Ident(ident) => &ident.sym,
+            Expr::Member(MemberExpr {
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => &prop.sym,
+            _ => return,
+        };
+        if !is_hook_name(name) {
+            return;
+        }
+
+        let mut key = pat.map(source_code).unwrap_or_default();
+        let initial_state = match &**name {
+            "useState" => call_expr.args.first(),
+            "useReducer" => call_expr.args.get(1),
+            _ => None,
+        };
+        if let Some(initial_state) = initial_state {
+            key.push_str(&format!("({})", source_code(&*initial_state.expr)));
+        }
+
+        self.hooks.push(Hook {
+            name: name.to_string(),
+            key,
+            callee: *callee.clone(),
+        });
+    }
+}
+
+/// Returns the code of the node in a single line (eg. `{ value: initial }`).
+fn source_code<N>(node: &N) -> String
+where
+    N: Node + Clone + VisitMutWith<DropSpan>,
+{
+    emit_code(node, false)
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Visit for HookCollector {
+    noop_visit_type!();
+
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        if let Some(Expr::Call(call_expr)) = var_declarator.init.as_deref() {
+            self.collect(call_expr, Some(&var_declarator.name));
+            call_expr.visit_children_with(self);
+        } else {
+            var_declarator.visit_children_with(self);
+        }
+    }
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        self.collect(call_expr, None);
+        call_expr.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_names() {
+        assert!(is_hook_name("useState"));
+        assert!(is_hook_name("use"));
+        assert!(!is_hook_name("user"));
+        assert!(!is_hook_name("usefulValue"));
+    }
+}
@@ -0,0 +1,96 @@
+use swc_core::{
+    common::EqIgnoreSpan,
+    ecma::{ast::*, utils::quote_ident},
+};
+
+use crate::{
+    constants::MODULE_HELPER_NAME,
+    error::ConfigError,
+    helpers::{global_var_expr, obj_member_expr},
+};
+
+/// Global object and namespace of the global module registry.
+///
+/// eg. `global.__modules`, `globalThis.__app_modules`
+#[derive(Clone, Debug)]
+pub struct ModuleRegistry {
+    global_object: String,
+    namespace: String,
+}
+
+impl ModuleRegistry {
+    /// - `global_object`: Root expression (eg. `globalThis`, `self`, `window` or `app.modules`)
+    /// - `namespace`: Property name of the registry (eg. `__modules`)
+    ///
+    /// Returns an error if the names are not valid identifiers.
+    pub fn new(global_object: &str, namespace: &str) -> Result<Self, ConfigError> {
+        global_object
+            .split('.')
+            .try_for_each(Ident::verify_symbol)
+            .map_err(|_| {
+                ConfigError::new("globalObject", "expected identifiers separated by `.`")
+            })?;
+        Ident::verify_symbol(namespace)
+            .map_err(|_| ConfigError::new("namespace", "expected an identifier"))?;
+
+        Ok(ModuleRegistry {
+            global_object: global_object.to_string(),
+            namespace: namespace.to_string(),
+        })
+    }
+
+    /// Returns an expression of the registry.
+    ///
+    /// eg. `global.__modules`
+    pub fn expr(&self) -> Expr {
+        obj_member_expr(
+            global_var_expr(&self.global_object),
+            quote_ident!(self.namespace.as_str()),
+        )
+    }
+
+    /// Returns an expression of the registry API.
+    ///
+    /// eg. `global.__modules.import`
+    pub fn api(&self, name: &str) -> Expr {
+        obj_member_expr(self.expr(), quote_ident!(name))
+    }
+
+    /// Returns `true` if the callee is the registry API.
+    pub fn is_api(&self, callee: &Callee, name: &str) -> bool {
+        match callee {
+            Callee::Expr(callee) => (**callee).eq_ignore_span(&self.api(name)),
+            _ => false,
+        }
+    }
+
+    /// Returns an expression of the registry helper.
+    ///
+    /// eg. `global.__modules.helpers.asWildcard`
+    pub fn helper(&self, name: &str) -> Expr {
+        obj_member_expr(self.api(MODULE_HELPER_NAME), quote_ident!(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert!(ModuleRegistry::new("globalThis", "__modules").is_ok());
+        assert!(ModuleRegistry::new("window.app", "$modules").is_ok());
+        assert_eq!(
+            ModuleRegistry::new("window.", "__modules")
+                .unwrap_err()
+                .option(),
+            "globalObject"
+        );
+        assert_eq!(
+            ModuleRegistry::new("self", "__app-modules")
+                .unwrap_err()
+                .option(),
+            "namespace"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use swc_core::common::{errors::HANDLER, Span};
+
+use crate::externals::External;
+
+/// Resolves import sources of the module.
+///
+/// `ModuleResolver` is the default implementation (`externals`, `moduleIds` and `resolve` options).
+pub trait Resolver {
+    /// Returns the actual module id of the import source.
+    ///
+    /// Sources that are not resolved (`None`) are used as the module id as is.
+    fn resolve(&self, src: &str) -> Option<String>;
+
+    /// Returns `true` if the import source is an external module.
+    fn is_external(&self, src: &str) -> bool;
+
+    /// Returns the provider of the external module.
+    ///
+    /// Defaults to the external registry keyed by the source.
+    fn external(&self, src: &str) -> Option<External> {
+        self.is_external(src).then(|| External::Registry {
+            key: src.to_string(),
+            version: None,
+            required_version: None,
+        })
+    }
+
+    /// Returns the identifier name of the imported module.
+    ///
+    /// eg. `_react_native` for `react-native`
+    fn ident_name(&self, src: &str) -> String {
+        format!("_{src}")
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+/// Reports the import source that is neither resolved nor external (strict mode).
+///
+/// Emits an error diagnostic with the span of the source (ignored if `HANDLER` is not set).
+pub fn report_unresolved(resolver: &dyn Resolver, src: &str, span: Span) {
+    if !HANDLER.is_set() || resolver.resolve(src).is_some() || resolver.is_external(src) {
+        return;
+    }
+
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_err(
+                span,
+                &format!("unresolved import source `{src}` (strict mode)"),
+            )
+            .emit()
+    });
+}
@@ -0,0 +1,198 @@
+use std::collections::{BTreeMap, BTreeSet};
+use swc_core::ecma::{
+    ast::*,
+    visit::{noop_visit_type, Visit, VisitMutWith, VisitWith},
+};
+
+use crate::{
+    boundary::collect_components,
+    esm_collector::{EsModuleCollector, ModuleType},
+};
+
+/// Kind of the exported value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportKind {
+    /// React component (eg. `export function App() {}`).
+    Component,
+    /// Other values (eg. `export const theme = {}`).
+    Value,
+    /// Re-exported from the other module (eg. `export { Button } from './Button'`).
+    ReExport,
+}
+
+/// Exports and dependencies of the module.
+///
+/// Compare two versions of the module with [`ModuleSignature::diff`]
+/// to decide between a hot update and a full reload.
+///
+/// ```
+/// use swc_core::ecma::ast::Module;
+/// use swc_global_module::ModuleSignature;
+///
+/// fn requires_reload(prev: &Module, next: &Module) -> bool {
+///     let diff = ModuleSignature::new(prev).diff(&ModuleSignature::new(next));
+///     diff.requires_reload()
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModuleSignature {
+    /// Exported names (`default` for the default export) and their kinds.
+    pub exports: BTreeMap<String, ExportKind>,
+    /// Sources of the export all statements (`export * from '...'`).
+    pub export_all: BTreeSet<String>,
+    /// Import sources of the imports, re-exports and `require` calls.
+    pub deps: BTreeSet<String>,
+}
+
+impl ModuleSignature {
+    pub fn new(module: &Module) -> Self {
+        let mut module = module.clone();
+        let mut esm_collector = EsModuleCollector::new(true);
+        module.visit_mut_with(&mut esm_collector);
+
+        let components = collect_components(&module.body, &esm_collector.exports);
+        let mut signature = ModuleSignature::default();
+
+        esm_collector.exports.iter().for_each(|export| {
+            let re_export_src = esm_collector
+                .imports
+                .iter()
+                .find(|import| import.as_export && import.ident.to_id() == export.ident.to_id())
+                .map(|import| import.module_src.clone());
+
+            let name = match (&export.module_type, &export.as_ident) {
+                (ModuleType::NamespaceOrAll, None) => {
+                    signature.export_all.extend(re_export_src);
+                    return;
+                }
+                (ModuleType::Default, _) => String::from("default"),
+                (_, Some(as_ident)) => as_ident.sym.to_string(),
+                (_, None) => export.ident.sym.to_string(),
+            };
+            let kind = if re_export_src.is_some() {
+                ExportKind::ReExport
+            } else if components.contains(&export.ident.to_id()) {
+                ExportKind::Component
+            } else {
+                ExportKind::Value
+            };
+            signature.exports.insert(name, kind);
+        });
+
+        signature.deps.extend(
+            esm_collector
+                .imports
+                .iter()
+                .map(|import| import.module_src.clone()),
+        );
+        let mut require_collector = RequireCollector::default();
+        module.visit_with(&mut require_collector);
+        signature.deps.extend(require_collector.srcs);
+
+        signature
+    }
+
+    /// Returns the changes from this signature to the `next` signature.
+    pub fn diff(&self, next: &ModuleSignature) -> SignatureDiff {
+        let mut diff = SignatureDiff::default();
+
+        self.exports
+            .iter()
+            .for_each(|(name, kind)| match next.exports.get(name) {
+                None => diff.removed.push(name.clone()),
+                Some(next_kind) if next_kind != kind => diff.changed.push(ExportChange {
+                    name: name.clone(),
+                    prev: *kind,
+                    next: *next_kind,
+                }),
+                _ => {}
+            });
+        diff.added = next
+            .exports
+            .keys()
+            .filter(|name| !self.exports.contains_key(*name))
+            .cloned()
+            .collect();
+
+        diff.removed_export_all = self
+            .export_all
+            .difference(&next.export_all)
+            .cloned()
+            .collect();
+        diff.added_export_all = next
+            .export_all
+            .difference(&self.export_all)
+            .cloned()
+            .collect();
+        diff.removed_deps = self.deps.difference(&next.deps).cloned().collect();
+        diff.added_deps = next.deps.difference(&self.deps).cloned().collect();
+
+        diff
+    }
+}
+
+/// Kind change of the export.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportChange {
+    pub name: String,
+    pub prev: ExportKind,
+    pub next: ExportKind,
+}
+
+/// Changes between two signatures of the module.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SignatureDiff {
+    /// Names of the added exports.
+    pub added: Vec<String>,
+    /// Names of the removed exports.
+    pub removed: Vec<String>,
+    /// Exports whose kind is changed (eg. component to value).
+    pub changed: Vec<ExportChange>,
+    /// Sources of the added export all statements.
+    pub added_export_all: Vec<String>,
+    /// Sources of the removed export all statements.
+    pub removed_export_all: Vec<String>,
+    /// Added import sources.
+    pub added_deps: Vec<String>,
+    /// Removed import sources.
+    pub removed_deps: Vec<String>,
+}
+
+impl SignatureDiff {
+    /// Returns `true` if the signature is not changed.
+    pub fn is_empty(&self) -> bool {
+        self == &SignatureDiff::default()
+    }
+
+    /// Returns `true` if the importers may be broken by the update
+    /// (exports are removed or their kinds are changed).
+    pub fn requires_reload(&self) -> bool {
+        !self.removed.is_empty() || !self.changed.is_empty() || !self.removed_export_all.is_empty()
+    }
+}
+
+/// Collects sources of the `require` calls (eg. `require('./utils')`).
+#[derive(Default)]
+struct RequireCollector {
+    srcs: Vec<String>,
+}
+
+impl Visit for RequireCollector {
+    noop_visit_type!();
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.visit_children_with(self);
+
+        let Callee::Expr(callee) = &call_expr.callee else {
+            return;
+        };
+        if !matches!(&**callee, Expr::Ident(ident) if ident.sym == "require") {
+            return;
+        }
+        if let [ExprOrSpread { spread: None, expr }] = call_expr.args.as_slice() {
+            if let Expr::Lit(Lit::Str(src)) = &**expr {
+                self.srcs.push(src.value.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use swc_core::ecma::{
+    ast::*,
+    utils::quote_ident,
+    visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+};
+
+use crate::{
+    constants::{CJS_API_NAME, DEFINE_API_NAME, ESM_API_NAME},
+    helpers::emit_code,
+    registry::ModuleRegistry,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Returns the content hash of the module (FNV-1a 64-bit of the emitted code in hex).
+///
+/// Spans and syntax contexts are dropped before emitting
+/// so that the hash is deterministic across runs and machines.
+pub fn module_version(module: &Module) -> String {
+    format!("{:016x}", fnv1a(emit_code(module, true).as_bytes()))
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Adds `version` to the metadata of the registration calls.
+///
+/// eg. `global.__modules.esm("module_id", exports, { deps, externals, version: "..." })`
+pub struct VersionInjector<'a> {
+    registry: &'a ModuleRegistry,
+    version: String,
+}
+
+impl<'a> VersionInjector<'a> {
+    pub fn new(registry: &'a ModuleRegistry, version: String) -> Self {
+        VersionInjector { registry, version }
+    }
+
+    fn meta_index(&self, callee: &Callee) -> Option<usize> {
+        if self.registry.is_api(callee, ESM_API_NAME) {
+            Some(2)
+        } else if self.registry.is_api(callee, CJS_API_NAME)
+            || self.registry.is_api(callee, DEFINE_API_NAME)
+        {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+impl VisitMut for VersionInjector<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+        call_expr.visit_mut_children_with(self);
+
+        let Some(index) = self.meta_index(&call_expr.callee) else {
+            return;
+        };
+        if let Some(Expr::Object(meta)) = call_expr.args.get_mut(index).map(|arg| &mut *arg.expr) {
+            meta.props.push(
+                Prop::KeyValue(KeyValueProp {
+                    key: quote_ident!("version").into(),
+                    value: Expr::from(self.version.as_str()).into(),
+                })
+                .into(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_hash() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+}
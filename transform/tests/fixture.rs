@@ -1,46 +1,267 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
+use swc_core::ecma::visit::Fold;
 use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms_testing::test_fixture;
-use swc_global_module::global_module;
+use swc_ecma_transforms_testing::{test_fixture, FixtureTestConfig};
+use swc_global_module::{
+    global_module, global_module_with_resolver, Config, ConfigOverride, ExternalRule, ModuleFormat,
+    ModuleIdRule, ModuleIdStrategy, ModuleIds, ResolveOptions, Resolver,
+};
+
+/// Resolver that maps sources to the ids of the bundler graph.
+struct GraphResolver {
+    graph: HashMap<&'static str, &'static str>,
+}
+
+impl Resolver for GraphResolver {
+    fn resolve(&self, src: &str) -> Option<String> {
+        self.graph.get(src).map(|id| id.to_string())
+    }
+
+    fn is_external(&self, src: &str) -> bool {
+        src == "react"
+    }
+
+    fn ident_name(&self, src: &str) -> String {
+        format!("_{}", src.rsplit('/').next().unwrap_or(src))
+    }
+}
 
 // ESM
 #[testing::fixture("tests/fixture/**/input.js")]
 fn fixture(input: PathBuf) {
     let filename = input.to_string_lossy();
+    if filename.contains("resolve/packages") && !cfg!(feature = "node") {
+        // Packages are resolved from `node_modules` with the `node` feature only.
+        return;
+    }
     let output = input.with_file_name("output.js");
     let runtime = !filename.contains("non-runtime");
+    let strict = filename.contains("strict");
 
-    let external = if filename.contains("external") {
-        Some(String::from("^(react|react-native)"))
+    let externals = if filename.contains("format/metro/external") {
+        // Registry keys are the numeric module ids of the dependency map.
+        vec![
+            ExternalRule {
+                source: Some(String::from("react")),
+                key: Some(String::from("12345")),
+                ..Default::default()
+            },
+            ExternalRule {
+                source: Some(String::from("react-native")),
+                key: Some(String::from("67890")),
+                ..Default::default()
+            },
+        ]
+    } else if filename.contains("external_versions") {
+        vec![
+            ExternalRule {
+                source: Some(String::from("react")),
+                version: Some(String::from("18.2.0")),
+                required_version: Some(String::from("^18.0.0")),
+                ..Default::default()
+            },
+            ExternalRule {
+                bare: true,
+                from_package_json: true,
+                ..Default::default()
+            },
+        ]
+    } else if filename.contains("external_rules") {
+        vec![
+            ExternalRule {
+                source: Some(String::from("react")),
+                global: Some(String::from("window.React")),
+                ..Default::default()
+            },
+            ExternalRule {
+                pattern: Some(String::from("^react-native$")),
+                key: Some(String::from("rn")),
+                ..Default::default()
+            },
+            ExternalRule {
+                pattern: Some(String::from("^node:")),
+                require: true,
+                ..Default::default()
+            },
+            ExternalRule {
+                bare: true,
+                ..Default::default()
+            },
+        ]
+    } else if filename.contains("external") {
+        vec![ExternalRule::pattern("^(react|react-native)")]
     } else {
-        None
+        vec![]
     };
 
-    let module_ids = if filename.contains("module_ids") {
-        Some(HashMap::from([(
+    let module_ids = if filename.contains("module_id_rules") {
+        Some(ModuleIds::Rules(vec![
+            ModuleIdRule::Exact {
+                source: String::from("react"),
+                id: String::from("12345"),
+            },
+            ModuleIdRule::Wildcard {
+                pattern: String::from("@app/components/*"),
+                id: String::from("src/components/*/index.tsx"),
+            },
+            ModuleIdRule::Regex {
+                regex: String::from("^@app/(.+)$"),
+                id: String::from("src/$1.ts"),
+            },
+        ]))
+    } else if filename.contains("format/metro") {
+        // Metro requires numeric module ids.
+        Some(ModuleIds::from(HashMap::from([
+            (String::from("react"), String::from("12345")),
+            (String::from("./Button"), String::from("1")),
+            (String::from("./theme"), String::from("2")),
+            (String::from("./format"), String::from("3")),
+            (String::from("./legacy"), String::from("007")),
+        ])))
+    } else if filename.contains("module_ids") {
+        Some(ModuleIds::from(HashMap::from([(
             String::from("react"),
             String::from("12345"),
-        )]))
+        )])))
+    } else {
+        None
+    };
+
+    // Relative path from the crate root (eg. `tests/fixture/esm/resolve/relative/input.js`).
+    let relative_filename = input
+        .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(&input)
+        .to_string_lossy()
+        .to_string();
+
+    let resolve_options = if filename.contains("resolve") {
+        let resolve_options = ResolveOptions {
+            relative: true,
+            node_modules: filename.contains("packages"),
+            conditions: vec![String::from("react-native"), String::from("import")],
+            ..Default::default()
+        };
+        Some(if filename.contains("tsconfig") {
+            resolve_options
+                .with_tsconfig(Path::new(&relative_filename).with_file_name("tsconfig.json"))
+                .unwrap()
+        } else {
+            resolve_options
+        })
     } else {
         None
     };
 
+    let mut config = Config::default()
+        .with_runtime_module(runtime)
+        .with_strict(strict)
+        .with_externals(externals);
+
+    if let Some(module_ids) = module_ids {
+        config = config.with_module_ids(module_ids);
+    }
+
+    if let Some(resolve_options) = resolve_options {
+        config = config.with_resolve(resolve_options);
+    }
+
+    config = if filename.contains("module_id_strategy") {
+        // The module id is generated from the filename.
+        config
+            .with_module_id_strategy(ModuleIdStrategy::Template(String::from("[name]-[hash:8]")))
+            .with_root("tests/fixture/esm/resolve")
+    } else {
+        config.with_module_id("test.js")
+    };
+
+    if filename.contains("overrides") {
+        config = config
+            .with_exclude(vec![String::from("**/excluded/**")])
+            .with_override(ConfigOverride {
+                files: vec![String::from("**/overrides/non_runtime/**")],
+                runtime_module: Some(false),
+                externals: Some(vec![ExternalRule::pattern("^react$")]),
+            });
+    }
+
+    if filename.contains("passthrough") {
+        config = config.with_passthrough_pattern("^(core-js|react/jsx-runtime|virtual:)");
+    }
+
+    if filename.contains("format/define") {
+        config = config.with_format(ModuleFormat::Define);
+    }
+
+    if filename.contains("format/system") {
+        config = config.with_format(ModuleFormat::System);
+    }
+
+    if filename.contains("format/metro") {
+        config = config
+            .with_format(ModuleFormat::Metro)
+            .with_module_id("100");
+    }
+
+    if filename.contains("format/iife") {
+        config = config.with_format(ModuleFormat::Iife);
+    }
+
+    if filename.contains("react_refresh") {
+        config = config.with_react_refresh(true);
+    }
+
+    if filename.contains("module_version") {
+        config = config.with_version(true);
+    }
+
+    if filename.contains("global_object") {
+        config = config
+            .with_global_object("globalThis")
+            .with_namespace("__app_modules");
+    }
+
     test_fixture(
         Syntax::Typescript(TsConfig {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| {
-            global_module(
-                String::from("test.js"),
-                runtime,
-                external.to_owned(),
-                module_ids.to_owned(),
+        &|tester| -> Box<dyn Fold> {
+            if filename.contains("custom_resolver") {
+                return Box::new(
+                    global_module_with_resolver(
+                        config.to_owned(),
+                        relative_filename.to_owned(),
+                        GraphResolver {
+                            graph: HashMap::from([
+                                ("@app/components/Button", "1"),
+                                ("../hooks/useTheme", "2"),
+                            ]),
+                        },
+                        Some(tester.comments.clone()),
+                    )
+                    .unwrap(),
+                );
+            }
+
+            Box::new(
+                global_module(
+                    config.to_owned(),
+                    relative_filename.to_owned(),
+                    Some(tester.comments.clone()),
+                )
+                .unwrap(),
             )
         },
         &input,
         &output,
-        Default::default(),
+        FixtureTestConfig {
+            // Diagnostics are compared with `output.stderr`.
+            allow_error: strict || filename.contains("unsupported"),
+            ..Default::default()
+        },
     );
 }
@@ -1,4 +1,7 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 if (__DEV__) {
   module.exports = __cjs.exports.default = class MyClass {};
   module.exports = __cjs.exports.default = class {};
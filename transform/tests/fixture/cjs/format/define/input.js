@@ -0,0 +1,7 @@
+const { format } = require('./format');
+
+function print(value) {
+  console.log(format(value));
+}
+
+module.exports = print;
@@ -0,0 +1,6 @@
+const { format } = require('./format');
+
+exports.print = function print(value) {
+  console.log(format(value));
+};
+exports.format = format;
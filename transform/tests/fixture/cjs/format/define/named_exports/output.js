@@ -0,0 +1,11 @@
+global.__modules.define("test.js", {
+  deps: ["./format"],
+  externals: []
+}, (require, exports, hot, module)=>{
+  const __cjs = exports.cjs();
+  const { format } = require.cjs("./format");
+  module.exports.print = __cjs.exports.print = function print(value) {
+    console.log(format(value));
+  };
+  module.exports.format = __cjs.exports.format = format;
+});
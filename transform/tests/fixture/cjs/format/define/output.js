@@ -0,0 +1,11 @@
+global.__modules.define("test.js", {
+  deps: ["./format"],
+  externals: []
+}, (require, exports, hot, module)=>{
+  const __cjs = exports.cjs();
+  const { format } = require.cjs("./format");
+  function print(value) {
+    console.log(format(value));
+  }
+  module.exports = __cjs.exports.default = print;
+});
@@ -0,0 +1,11 @@
+(function() {
+  const __cjs = global.__modules.cjs("test.js", {
+    deps: ["./format"],
+    externals: []
+  });
+  const { format } = global.__modules.require("./format");
+  function print(value) {
+    console.log(format(value));
+  }
+  module.exports = __cjs.exports.default = print;
+})();
@@ -0,0 +1,8 @@
+__d(function(global, _$$_REQUIRE, _$$_IMPORT_DEFAULT, _$$_IMPORT_ALL, module, exports, _dependencyMap) {
+  const __cjs = { exports: {} };
+  const { format } = _$$_REQUIRE(_dependencyMap[0]);
+  function print(value) {
+    console.log(format(value));
+  }
+  module.exports = __cjs.exports.default = print;
+}, 100, [3]);
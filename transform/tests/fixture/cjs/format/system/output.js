@@ -0,0 +1,20 @@
+System.register("test.js", ["./format"], function(_export, _context) {
+  var __dep, __cjs, format;
+  var module = { exports: {} };
+  function print(value) {
+    console.log(format(value));
+  }
+  return {
+    setters: [
+      function(_m) {
+        __dep = _m;
+      }
+    ],
+    execute: function() {
+      __cjs = { exports: { __cjs: true } };
+      ({ format } = __dep.__cjs ? __dep.default : __dep);
+      module.exports = __cjs.exports.default = print;
+      _export(__cjs.exports);
+    }
+  };
+});
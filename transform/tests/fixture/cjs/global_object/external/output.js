@@ -0,0 +1,7 @@
+const __cjs = globalThis.__app_modules.cjs("test.js", {
+  deps: ["./utils"],
+  externals: ["react"]
+});
+const React = globalThis.__app_modules.external("react");
+const utils = globalThis.__app_modules.require("./utils");
+module.exports = __cjs.exports.default = utils(React);
@@ -0,0 +1,10 @@
+const { render } = require('./render');
+
+module.exports = function App() {
+  return render();
+};
+
+if (module.hot) {
+  module.hot.accept();
+  module.hot.dispose(() => module.hot.invalidate());
+}
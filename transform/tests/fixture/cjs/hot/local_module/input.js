@@ -0,0 +1,5 @@
+function createModule(module) {
+  return module.hot ? module : null;
+}
+
+module.exports = createModule({ hot: true });
@@ -0,0 +1,8 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
+function createModule(module) {
+  return module.hot ? module : null;
+}
+module.exports = __cjs.exports.default = createModule({ hot: true });
@@ -0,0 +1,13 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: ["./render"],
+  externals: [],
+  boundary: "self"
+});
+const { render } = global.__modules.require("./render");
+module.exports = __cjs.exports.default = function App() {
+  return render();
+};
+if (global.__modules.hot("test.js")) {
+  global.__modules.hot("test.js").accept();
+  global.__modules.hot("test.js").dispose(()=>global.__modules.hot("test.js").invalidate());
+}
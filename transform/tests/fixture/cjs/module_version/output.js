@@ -0,0 +1,7 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: ["./format"],
+  externals: [],
+  version: "8f9495ca470031be"
+});
+const { format } = global.__modules.require("./format");
+module.exports = __cjs.exports.default = format('label');
@@ -1,4 +1,7 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 if (__DEV__) {
   exports.named = __cjs.exports.named = function myFunction() {};
   exports.anonymous = __cjs.exports.anonymous = function() {};
@@ -1,3 +1,6 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 module.exports = __cjs.exports.default = class MyClass {};
 module.exports = __cjs.exports.default = class {};
@@ -1,2 +1,5 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 module.exports = __cjs.exports.default = 'value';
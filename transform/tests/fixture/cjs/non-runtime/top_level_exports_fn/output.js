@@ -1,4 +1,7 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 module.exports = __cjs.exports.default = function myFunction() {};
 module.exports = __cjs.exports.default = function() {};
 module.exports = __cjs.exports.default = ()=>{};
@@ -1,3 +1,6 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 exports.named = __cjs.exports.named = class MyClass {};
 exports.anonymous = __cjs.exports.anonymous = class {};
@@ -1,2 +1,5 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 exports.named = __cjs.exports.named = 'value';
@@ -1,4 +1,7 @@
-const __cjs = global.__modules.cjs("test.js");
+const __cjs = global.__modules.cjs("test.js", {
+  deps: [],
+  externals: []
+});
 exports.named = __cjs.exports.named = function myFunction() {};
 exports.anonymous = __cjs.exports.anonymous = function() {};
 exports.anonymousArrow = __cjs.exports.anonymousArrow = ()=>{};
@@ -0,0 +1,8 @@
+const core = require('@app/core');
+const utils = global.requireWrapper(require('@app/utils'));
+
+function getCore() {
+  return require('@app/core');
+}
+
+exports.getCore = getCore;
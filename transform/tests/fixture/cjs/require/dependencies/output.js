@@ -0,0 +1,10 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: ["@app/core", "@app/utils"],
+  externals: []
+});
+const core = global.__modules.require("@app/core");
+const utils = global.requireWrapper(global.__modules.require("@app/utils"));
+function getCore() {
+  return global.__modules.require("@app/core");
+}
+exports.getCore = __cjs.exports.getCore = getCore;
@@ -0,0 +1,7 @@
+const React = require('react');
+const { View } = require('react-native');
+const fs = require('node:fs');
+const { debounce } = require('lodash');
+const { Button } = require('./Button');
+
+module.exports = debounce(() => React.createElement(View, null, Button(fs)));
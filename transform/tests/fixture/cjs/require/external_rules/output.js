@@ -0,0 +1,10 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: ["./Button"],
+  externals: ["lodash", "rn"]
+});
+const React = window.React;
+const { View } = global.__modules.external("rn");
+const fs = require('node:fs');
+const { debounce } = global.__modules.external("lodash");
+const { Button } = global.__modules.require("./Button");
+module.exports = __cjs.exports.default = debounce(() => React.createElement(View, null, Button(fs)));
@@ -0,0 +1,4 @@
+const React = require('react');
+const utils = require('./utils');
+
+module.exports = utils(React);
@@ -0,0 +1,7 @@
+const __cjs = global.__modules.cjs("test.js", {
+  deps: ["./utils", "12345"],
+  externals: []
+});
+const React = global.__modules.require("12345");
+const utils = global.__modules.require("./utils");
+module.exports = __cjs.exports.default = utils(React);
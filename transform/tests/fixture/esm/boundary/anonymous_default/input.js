@@ -0,0 +1,7 @@
+import React from 'react';
+
+function Form() {
+  return React.createElement('form');
+}
+
+export default () => React.createElement(Form);
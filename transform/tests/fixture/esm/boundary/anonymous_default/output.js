@@ -0,0 +1,10 @@
+const _react = global.__modules.import("react");
+const React = _react.default;
+function Form() {
+  return React.createElement('form');
+}
+const __export_default = ()=>React.createElement(Form);
+global.__modules.esm("test.js", { default: __export_default }, {
+  deps: ["react"],
+  externals: []
+});
@@ -0,0 +1,11 @@
+import React, { forwardRef, memo } from 'react';
+
+const Input = forwardRef((props, ref) => React.createElement('input', { ...props, ref }));
+
+function Form() {
+  return React.createElement('form', null, React.createElement(Input));
+}
+
+export { Input, Form };
+
+export default memo(Form);
@@ -0,0 +1,14 @@
+const _react = global.__modules.import("react");
+const React = _react.default;
+const forwardRef = _react.forwardRef;
+const memo = _react.memo;
+const Input = forwardRef((props, ref)=>React.createElement('input', { ...props, ref }));
+function Form() {
+  return React.createElement('form', null, React.createElement(Input));
+}
+const __export_default = memo(Form);
+global.__modules.esm("test.js", { Input, Form, default: __export_default }, {
+  deps: ["react"],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,8 @@
+import React from 'react';
+import { createConfig } from './config';
+
+export const CONFIG = createConfig();
+
+export function App() {
+  return React.createElement('div', null, CONFIG.title);
+}
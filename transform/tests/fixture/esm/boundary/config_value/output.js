@@ -0,0 +1,12 @@
+const ___config = global.__modules.import("./config");
+const _react = global.__modules.import("react");
+const React = _react.default;
+const createConfig = ___config.createConfig;
+const CONFIG = createConfig();
+function App() {
+  return React.createElement('div', null, CONFIG.title);
+}
+global.__modules.esm("test.js", { CONFIG, App }, {
+  deps: ["./config", "react"],
+  externals: []
+});
@@ -0,0 +1,7 @@
+import React from 'react';
+
+export class NotFoundError extends Error {}
+
+export function App() {
+  return React.createElement('div');
+}
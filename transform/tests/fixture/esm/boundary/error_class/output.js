@@ -0,0 +1,11 @@
+const _react = global.__modules.import("react");
+const React = _react.default;
+class NotFoundError extends Error {
+}
+function App() {
+  return React.createElement('div');
+}
+global.__modules.esm("test.js", { NotFoundError, App }, {
+  deps: ["react"],
+  externals: []
+});
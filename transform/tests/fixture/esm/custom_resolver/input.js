@@ -0,0 +1,8 @@
+import React from 'react';
+import { Button } from '@app/components/Button';
+import { useTheme } from '../hooks/useTheme';
+
+export function Component() {
+  const theme = useTheme();
+  return Button({ theme });
+}
@@ -0,0 +1,15 @@
+const _Button = global.__modules.import("1");
+const _useTheme = global.__modules.import("2");
+const _react = global.__modules.external("react");
+const React = _react.default;
+const Button = _Button.Button;
+const useTheme = _useTheme.useTheme;
+function Component() {
+  const theme = useTheme();
+  return Button({ theme });
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["1", "2"],
+  externals: ["react"],
+  boundary: "component"
+});
@@ -1,4 +1,7 @@
 class ClassDecl {}
 global.__modules.esm("test.js", {
   default: ClassDecl
+}, {
+  deps: [],
+  externals: []
 });
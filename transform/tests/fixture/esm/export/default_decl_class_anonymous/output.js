@@ -1,4 +1,7 @@
 class __Class {}
 global.__modules.esm("test.js", {
   default: __Class
+}, {
+  deps: [],
+  externals: []
 });
@@ -1,4 +1,7 @@
 function fn() {}
 global.__modules.esm("test.js", {
   default: fn
+}, {
+  deps: [],
+  externals: []
 });
@@ -1,4 +1,7 @@
 function __fn() {}
 global.__modules.esm("test.js", {
   default: __fn
+}, {
+  deps: [],
+  externals: []
 });
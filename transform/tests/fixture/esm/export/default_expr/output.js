@@ -1,4 +1,7 @@
 const __export_default = 0;
 global.__modules.esm("test.js", {
   default: __export_default
+}, {
+  deps: [],
+  externals: []
 });
@@ -24,4 +24,7 @@ global.__modules.esm("test.js", {
   default: __Class,
   app,
   useCustomHook
+}, {
+  deps: ["@app/components", "@app/core", "@app/hooks", "react"],
+  externals: []
 });
@@ -1,3 +1,6 @@
 const plain = 0;
 const beforeRename = 1;
-global.__modules.esm("test.js", { plain, afterRename: beforeRename });
+global.__modules.esm("test.js", { plain, afterRename: beforeRename }, {
+  deps: [],
+  externals: []
+});
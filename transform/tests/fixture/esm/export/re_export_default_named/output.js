@@ -5,4 +5,7 @@ const __re_export1 = _b.default;
 global.__modules.esm("test.js", {
   A: __re_export,
   B: __re_export1
+}, {
+  deps: ["a", "b"],
+  externals: []
 });
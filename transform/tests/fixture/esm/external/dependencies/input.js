@@ -0,0 +1,10 @@
+import React from 'react';
+import { View } from 'react-native';
+import { Button } from '@app/components';
+import * as utils from '@app/utils';
+
+export function Component() {
+  return null;
+}
+
+export * from '@app/core';
@@ -0,0 +1,17 @@
+const __app_components = global.__modules.import("@app/components");
+const __app_core = global.__modules.import("@app/core");
+const __app_utils = global.__modules.import("@app/utils");
+const _react = global.__modules.external("react");
+const _react_native = global.__modules.external("react-native");
+const React = _react.default;
+const View = _react_native.View;
+const Button = __app_components.Button;
+const utils = global.__modules.helpers.asWildcard(__app_utils);
+const __re_export_all = global.__modules.helpers.asWildcard(__app_core);
+function Component() {
+  return null;
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["@app/components", "@app/core", "@app/utils"],
+  externals: ["react", "react-native"]
+}, __re_export_all);
@@ -0,0 +1,10 @@
+import React, { useState } from 'react';
+import { View } from 'react-native';
+import * as fs from 'node:fs';
+import { debounce } from 'lodash';
+import { Button } from './Button';
+
+export function Component() {
+  const [state] = useState(debounce(() => fs.readFileSync('a')));
+  return React.createElement(View, null, Button(state));
+}
@@ -0,0 +1,18 @@
+import * as __external from "react-native";
+global.__modules.external("rn", __external);
+import * as __external1 from "lodash";
+global.__modules.external("lodash", __external1);
+import React, { useState } from 'react';
+import { View } from 'react-native';
+import * as fs from 'node:fs';
+import { debounce } from 'lodash';
+import { Button } from './Button';
+export function Component() {
+  const [state] = useState(debounce(() => fs.readFileSync('a')));
+  return React.createElement(View, null, Button(state));
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["./Button"],
+  externals: ["lodash", "rn"],
+  boundary: "component"
+});
@@ -0,0 +1,20 @@
+const ___Button = global.__modules.import("./Button");
+const _lodash = global.__modules.external("lodash");
+const _node_fs = global.__modules.helpers.asModule(require("node:fs"));
+const _react = global.__modules.helpers.asModule(window.React);
+const _react_native = global.__modules.external("rn");
+const React = _react.default;
+const useState = _react.useState;
+const View = _react_native.View;
+const fs = global.__modules.helpers.asWildcard(_node_fs);
+const debounce = _lodash.debounce;
+const Button = ___Button.Button;
+function Component() {
+  const [state] = useState(debounce(() => fs.readFileSync('a')));
+  return React.createElement(View, null, Button(state));
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["./Button"],
+  externals: ["lodash", "rn"],
+  boundary: "component"
+});
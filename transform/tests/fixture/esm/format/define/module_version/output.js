@@ -0,0 +1,10 @@
+global.__modules.define("test.js", {
+  deps: ["./format"],
+  externals: [],
+  version: "79a33e1733e9811e"
+}, (require, exports, hot, module)=>{
+  const ___format = require("./format");
+  const format = ___format.format;
+  const label = format('label');
+  exports({ label });
+});
@@ -0,0 +1,21 @@
+global.__modules.define("test.js", {
+  deps: ["./Button", "./theme", "react"],
+  externals: [],
+  boundary: "self"
+}, (require, exports, hot, module)=>{
+  const ___Button = require("./Button");
+  const ___theme = require("./theme");
+  const _react = require("react");
+  const React = _react.default;
+  const useState = _react.useState;
+  const Button = ___Button.Button;
+  const __re_export_all = global.__modules.helpers.asWildcard(___theme);
+  function App() {
+    const [count, setCount] = useState(0);
+    return React.createElement(Button, { onClick: ()=>setCount(count + 1) }, count);
+  }
+  if (hot) {
+    hot.accept();
+  }
+  exports({ App }, __re_export_all);
+});
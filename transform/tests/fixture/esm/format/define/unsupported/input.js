@@ -0,0 +1,4 @@
+import { App } from './App';
+/* @global-module-keep */ import 'core-js/stable';
+
+export default App;
@@ -0,0 +1,10 @@
+global.__modules.define("test.js", {
+  deps: ["./App", "core-js/stable"],
+  externals: []
+}, (require, exports, hot, module)=>{
+  const ___App = require("./App");
+  const _core_js_stable = require("core-js/stable");
+  const App = ___App.App;
+  const __export_default = App;
+  exports({ default: __export_default });
+});
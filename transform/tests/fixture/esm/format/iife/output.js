@@ -0,0 +1,22 @@
+(function() {
+  "use strict";
+  const ___Button = global.__modules.import("./Button");
+  const ___theme = global.__modules.import("./theme");
+  const _react = global.__modules.import("react");
+  const React = _react.default;
+  const useState = _react.useState;
+  const Button = ___Button.Button;
+  const __re_export_all = global.__modules.helpers.asWildcard(___theme);
+  function App() {
+    const [count, setCount] = useState(0);
+    return React.createElement(Button, { onClick: ()=>setCount(count + 1) }, count);
+  }
+  if (global.__modules.hot("test.js")) {
+    global.__modules.hot("test.js").accept();
+  }
+  global.__modules.esm("test.js", { App }, {
+    deps: ["./Button", "./theme", "react"],
+    externals: [],
+    boundary: "self"
+  }, __re_export_all);
+})();
@@ -0,0 +1,10 @@
+import React, { useState } from 'react';
+import * as ReactNative from 'react-native';
+import { Button } from './Button';
+
+export * from './theme';
+
+export function App() {
+  const [count] = useState(0);
+  return React.createElement(ReactNative.View, null, Button(count));
+}
@@ -0,0 +1,23 @@
+__d(function(global, _$$_REQUIRE, _$$_IMPORT_DEFAULT, _$$_IMPORT_ALL, module, exports, _dependencyMap) {
+  const ___Button = _$$_IMPORT_ALL(_dependencyMap[0]);
+  const ___theme = _$$_IMPORT_ALL(_dependencyMap[1]);
+  const _react = _$$_IMPORT_ALL(_dependencyMap[2]);
+  const _react_native = _$$_IMPORT_ALL(_dependencyMap[3]);
+  const React = _$$_IMPORT_DEFAULT(_dependencyMap[2]);
+  const useState = _react.useState;
+  var ReactNative = {};
+  for(var _key in _react_native){
+    if (_key !== "default") ReactNative[_key] = _react_native[_key];
+  }
+  const Button = ___Button.Button;
+  var __re_export_all = {};
+  for(var _key1 in ___theme){
+    if (_key1 !== "default") __re_export_all[_key1] = ___theme[_key1];
+  }
+  function App() {
+    const [count] = useState(0);
+    return React.createElement(ReactNative.View, null, Button(count));
+  }
+  Object.defineProperty(exports, "__esModule", { value: true });
+  Object.assign(exports, __re_export_all, { App });
+}, 100, [1, 2, 12345, 67890]);
@@ -0,0 +1,14 @@
+/* @global-module id="42" */
+import React, { useState } from 'react';
+import { Button } from './Button';
+
+export * from './theme';
+
+export function App() {
+  const [count, setCount] = useState(0);
+  return React.createElement(Button, { onClick: () => setCount(count + 1) }, count);
+}
+
+if (import.meta.hot) {
+  import.meta.hot.accept();
+}
@@ -0,0 +1,21 @@
+/* @global-module id="42" */ __d(function(global, _$$_REQUIRE, _$$_IMPORT_DEFAULT, _$$_IMPORT_ALL, module, exports, _dependencyMap) {
+  const ___Button = _$$_IMPORT_ALL(_dependencyMap[0]);
+  const _react = _$$_IMPORT_ALL(_dependencyMap[1]);
+  const ___theme = _$$_IMPORT_ALL(_dependencyMap[2]);
+  const React = _$$_IMPORT_DEFAULT(_dependencyMap[1]);
+  const useState = _react.useState;
+  const Button = ___Button.Button;
+  var __re_export_all = {};
+  for(var _key in ___theme){
+    if (_key !== "default") __re_export_all[_key] = ___theme[_key];
+  }
+  function App() {
+    const [count, setCount] = useState(0);
+    return React.createElement(Button, { onClick: ()=>setCount(count + 1) }, count);
+  }
+  if (module.hot) {
+    module.hot.accept();
+  }
+  Object.defineProperty(exports, "__esModule", { value: true });
+  Object.assign(exports, __re_export_all, { App });
+}, 42, [1, 12345, 2]);
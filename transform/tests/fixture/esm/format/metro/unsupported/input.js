@@ -0,0 +1,4 @@
+import { legacy } from './legacy';
+import { format } from './utils';
+
+export const label = format(legacy);
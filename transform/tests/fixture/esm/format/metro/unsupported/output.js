@@ -0,0 +1,9 @@
+__d(function(global, _$$_REQUIRE, _$$_IMPORT_DEFAULT, _$$_IMPORT_ALL, module, exports, _dependencyMap) {
+  const ___utils = _$$_IMPORT_ALL(_dependencyMap[0]);
+  const ___legacy = _$$_IMPORT_ALL(_dependencyMap[1]);
+  const legacy = ___legacy.legacy;
+  const format = ___utils.format;
+  const label = format(legacy);
+  Object.defineProperty(exports, "__esModule", { value: true });
+  Object.assign(exports, { label });
+}, 100, ["./utils", "007"]);
@@ -0,0 +1,26 @@
+System.register("test.js", ["./Button", "react", "react-native"], function(_export, _context) {
+  var ___Button, _react, _react_native, React, useState, ReactNative, Button;
+  function App() {
+    const [count] = useState(0);
+    return React.createElement(ReactNative.View, null, Button(count));
+  }
+  _export("App", App);
+  return {
+    setters: [
+      function(_m) {
+        ___Button = _m;
+        Button = _m.Button;
+      },
+      function(_m) {
+        _react = _m;
+        React = _m.default;
+        useState = _m.useState;
+      },
+      function(_m) {
+        _react_native = _m;
+        ReactNative = _m;
+      }
+    ],
+    execute: function() {}
+  };
+});
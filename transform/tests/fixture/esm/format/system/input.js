@@ -0,0 +1,23 @@
+import React, { useState } from 'react';
+import { Button } from './Button';
+
+export * from './theme';
+export { useTheme } from './theme';
+
+export let renderCount = 0;
+
+export function App() {
+  const [count, setCount] = useState(0);
+  renderCount++;
+  return React.createElement(Button, { onClick: () => setCount(count + 1) }, count);
+}
+
+export function reset() {
+  renderCount = 0;
+}
+
+export function nextRenderCount() {
+  return renderCount++;
+}
+
+export default class Page extends React.Component {}
@@ -0,0 +1,44 @@
+System.register("test.js", ["./Button", "./theme", "react"], function(_export, _context) {
+  var ___Button, ___theme, _react, React, useState, Button, __re_export, renderCount, Page, _value;
+  function App() {
+    const [count, setCount] = useState(0);
+    _export("renderCount", ++renderCount);
+    return React.createElement(Button, { onClick: ()=>setCount(count + 1) }, count);
+  }
+  _export("App", App);
+  function reset() {
+    _export("renderCount", renderCount = 0);
+  }
+  _export("reset", reset);
+  function nextRenderCount() {
+    return _value = renderCount++, _export("renderCount", renderCount), _value;
+  }
+  _export("nextRenderCount", nextRenderCount);
+  return {
+    setters: [
+      function(_m) {
+        ___Button = _m;
+        Button = _m.Button;
+      },
+      function(_m) {
+        ___theme = _m;
+        var _exportObj = {};
+        for(var _key in _m){
+          if (_key !== "default" && _key !== "useTheme" && _key !== "renderCount" && _key !== "App" && _key !== "reset" && _key !== "nextRenderCount") _exportObj[_key] = _m[_key];
+        }
+        _export(_exportObj);
+        _export("useTheme", __re_export = _m.useTheme);
+      },
+      function(_m) {
+        _react = _m;
+        React = _m.default;
+        useState = _m.useState;
+      }
+    ],
+    execute: function() {
+      _export("renderCount", renderCount = 0);
+      _export("default", Page = class Page extends React.Component {
+      });
+    }
+  };
+});
@@ -0,0 +1,7 @@
+import { App } from './App';
+
+export default App;
+
+if (import.meta.hot) {
+  import.meta.hot.accept();
+}
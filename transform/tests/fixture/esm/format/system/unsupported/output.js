@@ -0,0 +1,17 @@
+System.register("test.js", ["./App"], function(_export, _context) {
+  var ___App, App, __export_default;
+  return {
+    setters: [
+      function(_m) {
+        ___App = _m;
+        App = _m.App;
+      }
+    ],
+    execute: function() {
+      _export("default", __export_default = App);
+      if (global.__modules.hot("test.js")) {
+        global.__modules.hot("test.js").accept();
+      }
+    }
+  };
+});
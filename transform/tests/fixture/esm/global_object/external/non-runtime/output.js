@@ -0,0 +1,15 @@
+import * as __external from "react";
+globalThis.__app_modules.external("react", __external);
+import * as __re_export_all from "./components";
+import React, { useState } from 'react';
+import * as utils from './utils';
+import { Button } from './Button';
+export function App() {
+  const [count] = useState(utils.initial);
+  return React.createElement(Button, null, count);
+}
+export * from './components';
+globalThis.__app_modules.esm("test.js", { App }, {
+  deps: ["./Button", "./components", "./utils"],
+  externals: ["react"]
+}, __re_export_all);
@@ -0,0 +1,10 @@
+import React, { useState } from 'react';
+import * as utils from './utils';
+import { Button } from './Button';
+
+export function App() {
+  const [count] = useState(utils.initial);
+  return React.createElement(Button, null, count);
+}
+
+export * from './components';
@@ -0,0 +1,17 @@
+const ___Button = globalThis.__app_modules.import("./Button");
+const ___components = globalThis.__app_modules.import("./components");
+const ___utils = globalThis.__app_modules.import("./utils");
+const _react = globalThis.__app_modules.external("react");
+const React = _react.default;
+const useState = _react.useState;
+const utils = globalThis.__app_modules.helpers.asWildcard(___utils);
+const Button = ___Button.Button;
+const __re_export_all = globalThis.__app_modules.helpers.asWildcard(___components);
+function App() {
+  const [count] = useState(utils.initial);
+  return React.createElement(Button, null, count);
+}
+globalThis.__app_modules.esm("test.js", { App }, {
+  deps: ["./Button", "./components", "./utils"],
+  externals: ["react"]
+}, __re_export_all);
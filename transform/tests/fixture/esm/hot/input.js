@@ -0,0 +1,10 @@
+import { render } from './render';
+
+export const count = import.meta.hot?.data.count ?? 0;
+
+if (import.meta.hot) {
+  import.meta.hot.accept((module) => render(module.count));
+  import.meta.hot.dispose((data) => {
+    data.count = count;
+  });
+}
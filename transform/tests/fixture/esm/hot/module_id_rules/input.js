@@ -0,0 +1,9 @@
+import { Button } from '@app/components/Button';
+import { theme } from '@app/theme';
+
+export const App = () => Button(theme);
+
+if (import.meta.hot) {
+  import.meta.hot.accept('@app/components/Button', ({ Button }) => Button(theme));
+  import.meta.hot.accept(['react', '@app/theme', './local'], () => {});
+}
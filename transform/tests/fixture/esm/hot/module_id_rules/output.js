@@ -0,0 +1,14 @@
+const __app_components_Button = global.__modules.import("src/components/Button/index.tsx");
+const __app_theme = global.__modules.import("src/theme.ts");
+const Button = __app_components_Button.Button;
+const theme = __app_theme.theme;
+const App = () => Button(theme);
+if (global.__modules.hot("test.js")) {
+  global.__modules.hot("test.js").accept("src/components/Button/index.tsx", ({ Button }) => Button(theme));
+  global.__modules.hot("test.js").accept(["12345", "src/theme.ts", './local'], () => {});
+}
+global.__modules.esm("test.js", { App }, {
+  deps: ["src/components/Button/index.tsx", "src/theme.ts"],
+  externals: [],
+  boundary: "component"
+});
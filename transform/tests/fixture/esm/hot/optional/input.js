@@ -0,0 +1,3 @@
+export const count = 0;
+
+import.meta.hot?.accept();
@@ -0,0 +1,7 @@
+const count = 0;
+global.__modules.hot("test.js")?.accept();
+global.__modules.esm("test.js", { count }, {
+  deps: [],
+  externals: [],
+  boundary: "self"
+});
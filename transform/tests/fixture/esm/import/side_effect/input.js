@@ -0,0 +1,5 @@
+import './polyfill';
+import { Button } from './Button';
+import './styles.css';
+
+export const Page = () => import('./Page').then((mod) => mod.default);
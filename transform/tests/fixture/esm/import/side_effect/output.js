@@ -0,0 +1,10 @@
+const ___Button = global.__modules.import("./Button");
+const ___polyfill = global.__modules.import("./polyfill");
+const ___styles_css = global.__modules.import("./styles.css");
+const Button = ___Button.Button;
+const Page = () => import('./Page').then((mod) => mod.default);
+global.__modules.esm("test.js", { Page }, {
+  deps: ["./Button", "./Page", "./polyfill", "./styles.css"],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,8 @@
+import React from 'react';
+import { Button } from '@app/components/Button';
+import { useCustomHook } from '@app/hooks';
+import { transform } from '@swc/core';
+
+export function Component() {
+  return null;
+}
@@ -0,0 +1,16 @@
+const _react = global.__modules.import("12345");
+const __swc_core = global.__modules.import("@swc/core");
+const __app_components_Button = global.__modules.import("src/components/Button/index.tsx");
+const __app_hooks = global.__modules.import("src/hooks.ts");
+const React = _react.default;
+const Button = __app_components_Button.Button;
+const useCustomHook = __app_hooks.useCustomHook;
+const transform = __swc_core.transform;
+function Component() {
+  return null;
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["12345", "@swc/core", "src/components/Button/index.tsx", "src/hooks.ts"],
+  externals: [],
+  boundary: "component"
+});
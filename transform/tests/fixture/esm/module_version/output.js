@@ -0,0 +1,8 @@
+const ___format = global.__modules.import("./format");
+const format = ___format.format;
+const label = format('label');
+global.__modules.esm("test.js", { label }, {
+  deps: ["./format"],
+  externals: [],
+  version: "25f9e1a2d3fdc116"
+});
@@ -0,0 +1,13 @@
+import React, { forwardRef, memo } from 'react';
+const Input = forwardRef((props, ref)=>React.createElement('input', { ...props, ref }));
+function Form() {
+  return React.createElement('form', null, React.createElement(Input));
+}
+export { Input, Form };
+export default __export_default = memo(Form);
+var __export_default;
+global.__modules.esm("test.js", { Input, Form, default: __export_default }, {
+  deps: ["react"],
+  externals: [],
+  boundary: "component"
+});
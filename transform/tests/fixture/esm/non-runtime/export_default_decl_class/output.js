@@ -1,4 +1,7 @@
 export default class ClassDecl {}
 global.__modules.esm("test.js", {
   default: ClassDecl
+}, {
+  deps: [],
+  externals: []
 });
@@ -1,4 +1,7 @@
 export default class __Class {}
 global.__modules.esm("test.js", {
   default: __Class
+}, {
+  deps: [],
+  externals: []
 });
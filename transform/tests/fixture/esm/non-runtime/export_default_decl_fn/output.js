@@ -1,4 +1,7 @@
 export default function fn() {}
 global.__modules.esm("test.js", {
   default: fn
+}, {
+  deps: [],
+  externals: []
 });
@@ -1,4 +1,7 @@
 export default function __fn() {}
 global.__modules.esm("test.js", {
   default: __fn
+}, {
+  deps: [],
+  externals: []
 });
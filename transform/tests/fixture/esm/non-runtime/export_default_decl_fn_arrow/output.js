@@ -2,4 +2,7 @@ export default __export_default = ()=>{};
 var __export_default;
 global.__modules.esm("test.js", {
   default: __export_default
+}, {
+  deps: [],
+  externals: []
 });
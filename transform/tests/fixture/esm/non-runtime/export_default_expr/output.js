@@ -2,4 +2,7 @@ export default __export_default = 0;
 var __export_default;
 global.__modules.esm("test.js", {
   default: __export_default
+}, {
+  deps: [],
+  externals: []
 });
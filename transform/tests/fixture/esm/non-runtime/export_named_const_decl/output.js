@@ -1,2 +1,5 @@
 export const named = new Instance();
-global.__modules.esm("test.js", { named });
+global.__modules.esm("test.js", { named }, {
+  deps: [],
+  externals: []
+});
@@ -3,4 +3,7 @@ import { createElement } from 'react';
 export { createElement } from 'react';
 global.__modules.esm("test.js", {
   createElement: __re_export
+}, {
+  deps: ["react"],
+  externals: []
 });
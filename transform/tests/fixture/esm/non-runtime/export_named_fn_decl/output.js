@@ -1,4 +1,7 @@
 export function namedFunction() {
   console.log('body');
 }
-global.__modules.esm("test.js", { namedFunction });
+global.__modules.esm("test.js", { namedFunction }, {
+  deps: [],
+  externals: []
+});
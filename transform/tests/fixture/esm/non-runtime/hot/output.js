@@ -0,0 +1,13 @@
+import { render } from './render';
+export const count = global.__modules.hot("test.js")?.data.count ?? 0;
+if (global.__modules.hot("test.js")) {
+  global.__modules.hot("test.js").accept((module)=>render(module.count));
+  global.__modules.hot("test.js").dispose((data)=>{
+    data.count = count;
+  });
+}
+global.__modules.esm("test.js", { count }, {
+  deps: ["./render"],
+  externals: [],
+  boundary: "self"
+});
@@ -0,0 +1,7 @@
+import { format } from './format';
+export const label = format('label');
+global.__modules.esm("test.js", { label }, {
+  deps: ["./format"],
+  externals: [],
+  version: "59b92ef000c23d35"
+});
@@ -0,0 +1,14 @@
+/* @global-module-keep */ import 'core-js/stable';
+import React from 'react';
+// @global-module-keep
+import { worker } from './worker?worker';
+export function App() {
+  return React.createElement(worker);
+}
+global.__modules.esm("test.js", {
+  App
+}, {
+  deps: ["./worker?worker", "core-js/stable", "react"],
+  externals: [],
+  boundary: "component"
+});
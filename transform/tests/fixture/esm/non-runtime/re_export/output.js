@@ -6,4 +6,7 @@ global.__modules.esm("test.js", {
   a: __re_export,
   b: __re_export1,
   c: __re_export2
+}, {
+  deps: ["module"],
+  externals: []
 });
@@ -2,4 +2,7 @@ import * as __re_export from "module";
 export * as rename from 'module';
 global.__modules.esm("test.js", {
   rename: __re_export
+}, {
+  deps: ["module"],
+  externals: []
 });
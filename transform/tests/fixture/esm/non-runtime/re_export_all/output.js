@@ -1,3 +1,6 @@
 import * as __re_export_all from "module";
 export * from 'module';
-global.__modules.esm("test.js", {}, __re_export_all);
+global.__modules.esm("test.js", {}, {
+  deps: ["module"],
+  externals: []
+}, __re_export_all);
@@ -2,4 +2,7 @@ import __re_export from "module";
 export { default } from 'module';
 global.__modules.esm("test.js", {
   default: __re_export
+}, {
+  deps: ["module"],
+  externals: []
 });
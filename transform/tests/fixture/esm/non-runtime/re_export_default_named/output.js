@@ -5,4 +5,7 @@ export { default as B } from 'b';
 global.__modules.esm("test.js", {
   A: __re_export,
   B: __re_export1
+}, {
+  deps: ["a", "b"],
+  externals: []
 });
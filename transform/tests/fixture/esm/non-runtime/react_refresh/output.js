@@ -0,0 +1,35 @@
+import React, { useState, memo } from 'react';
+import { useTheme } from './hooks';
+const _s = $RefreshSig$();
+export function Counter() {
+  _s();
+  const [count, setCount] = useState(0);
+  const theme = useTheme();
+  const onPress = ()=>setCount(count + 1);
+  return React.createElement('button', { style: theme, onClick: onPress }, count);
+}
+const Title = ({ title })=>React.createElement('h1', null, title);
+export const Header = memo(()=>React.createElement(Title, { title: 'Header' }));
+export default class App extends React.Component {
+  render() {
+    return React.createElement(Counter);
+  }
+}
+export const formatCount = (count)=>count.toString();
+global.__modules.esm("test.js", { Counter, Header, default: App, formatCount }, {
+  deps: ["./hooks", "react"],
+  externals: []
+});
+_s(Counter, "useState{[count, setCount](0)}\nuseTheme{theme}", false, ()=>[useTheme]);
+$RefreshReg$(Counter, "test.js Counter");
+$RefreshReg$(Title, "test.js Title");
+$RefreshReg$(Header, "test.js Header");
+$RefreshReg$(App, "test.js App");
+if (global.__modules.isRefreshBoundary("test.js")) {
+  global.__modules.hot("test.js").accept();
+} else if (global.__modules.hot("test.js").data.__refreshed) {
+  global.__modules.hot("test.js").invalidate();
+}
+global.__modules.hot("test.js").dispose((data)=>{
+  data.__refreshed = true;
+});
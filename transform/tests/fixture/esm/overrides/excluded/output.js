@@ -0,0 +1,5 @@
+import React from 'react';
+import { Button } from './Button';
+export function App() {
+    return React.createElement(Button);
+}
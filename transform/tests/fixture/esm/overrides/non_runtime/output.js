@@ -0,0 +1,12 @@
+import * as __external from "react";
+global.__modules.external("react", __external);
+import React from 'react';
+import { Button } from './Button';
+export function App() {
+  return React.createElement(Button);
+}
+global.__modules.esm("test.js", { App }, {
+  deps: ["./Button"],
+  externals: ["react"],
+  boundary: "component"
+});
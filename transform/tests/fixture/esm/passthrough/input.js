@@ -0,0 +1,11 @@
+import 'core-js/stable';
+import { jsx as _jsx } from 'react/jsx-runtime';
+import { Button } from './Button';
+import routes from 'virtual:routes';
+
+export * from 'virtual:icons';
+export { theme } from './theme';
+
+export function App() {
+  return _jsx(Button, { routes });
+}
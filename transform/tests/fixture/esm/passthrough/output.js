@@ -0,0 +1,15 @@
+import 'core-js/stable';
+import { jsx as _jsx } from 'react/jsx-runtime';
+import routes from 'virtual:routes';
+export * from 'virtual:icons';
+const ___Button = global.__modules.import("./Button");
+const ___theme = global.__modules.import("./theme");
+const Button = ___Button.Button;
+const __re_export = ___theme.theme;
+function App() {
+  return _jsx(Button, { routes });
+}
+global.__modules.esm("test.js", { theme: __re_export, App }, {
+  deps: ["./Button", "./theme"],
+  externals: []
+});
@@ -0,0 +1,4 @@
+/* @global-module id="app/root" */
+import { App } from './App';
+
+export default App;
@@ -0,0 +1,9 @@
+/* @global-module id="app/root" */ const ___App = global.__modules.import("./App");
+const App = ___App.App;
+const __export_default = App;
+global.__modules.esm("app/root", {
+  default: __export_default
+}, {
+  deps: ["./App"],
+  externals: []
+});
@@ -0,0 +1,4 @@
+/* @global-module-ignore */
+import { App } from './App';
+
+export default App;
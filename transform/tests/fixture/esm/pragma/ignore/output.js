@@ -0,0 +1,2 @@
+/* @global-module-ignore */ import { App } from './App';
+export default App;
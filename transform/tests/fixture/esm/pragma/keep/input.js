@@ -0,0 +1,9 @@
+/* @global-module-keep */
+import 'core-js/stable';
+import React from 'react';
+// @global-module-keep
+import { worker } from './worker?worker';
+
+export function App() {
+  return React.createElement(worker);
+}
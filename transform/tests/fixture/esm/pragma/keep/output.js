@@ -0,0 +1,15 @@
+/* @global-module-keep */ import 'core-js/stable';
+// @global-module-keep
+import { worker } from './worker?worker';
+const _react = global.__modules.import("react");
+const React = _react.default;
+function App() {
+  return React.createElement(worker);
+}
+global.__modules.esm("test.js", {
+  App
+}, {
+  deps: ["react"],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,9 @@
+import { memo, useReducer } from 'react';
+import { reducer } from './reducer';
+
+function Form({ initial }) {
+  const [state, dispatch] = useReducer(reducer, { value: initial });
+  return state.value;
+}
+
+export default memo(Form);
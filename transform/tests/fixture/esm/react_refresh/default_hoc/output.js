@@ -0,0 +1,29 @@
+const _s = $RefreshSig$();
+var _c;
+const ___reducer = global.__modules.import("./reducer");
+const _react = global.__modules.import("react");
+const memo = _react.memo;
+const useReducer = _react.useReducer;
+const reducer = ___reducer.reducer;
+function Form({ initial }) {
+  _s();
+  const [state, dispatch] = useReducer(reducer, { value: initial });
+  return state.value;
+}
+const __export_default = _c = memo(Form);
+global.__modules.esm("test.js", { default: __export_default }, {
+  deps: ["./reducer", "react"],
+  externals: [],
+  boundary: "component"
+});
+_s(Form, "useReducer{[state, dispatch]({ value: initial })}");
+$RefreshReg$(Form, "test.js Form");
+$RefreshReg$(_c, "test.js %default%");
+if (global.__modules.isRefreshBoundary("test.js")) {
+  global.__modules.hot("test.js").accept();
+} else if (global.__modules.hot("test.js").data.__refreshed) {
+  global.__modules.hot("test.js").invalidate();
+}
+global.__modules.hot("test.js").dispose((data)=>{
+  data.__refreshed = true;
+});
@@ -0,0 +1,23 @@
+import React, { useState, memo } from 'react';
+import { useTheme } from './hooks';
+
+export function Counter() {
+  const [count, setCount] = useState(0);
+  const theme = useTheme();
+  const onPress = () => setCount(count + 1);
+  return React.createElement('button', { style: theme, onClick: onPress }, count);
+}
+
+const Title = ({ title }) => React.createElement('h1', null, title);
+
+const Label = ({ text }) => React.createElement('span', { style: useTheme() }, text);
+
+export const Header = memo(() => React.createElement(Title, { title: 'Header' }));
+
+export default class App extends React.Component {
+  render() {
+    return React.createElement(Counter);
+  }
+}
+
+export const formatCount = (count) => count.toString();
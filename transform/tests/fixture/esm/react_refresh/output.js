@@ -0,0 +1,46 @@
+const _s = $RefreshSig$();
+const _s1 = $RefreshSig$();
+const ___hooks = global.__modules.import("./hooks");
+const _react = global.__modules.import("react");
+const React = _react.default;
+const useState = _react.useState;
+const memo = _react.memo;
+const useTheme = ___hooks.useTheme;
+function Counter() {
+  _s();
+  const [count, setCount] = useState(0);
+  const theme = useTheme();
+  const onPress = ()=>setCount(count + 1);
+  return React.createElement('button', { style: theme, onClick: onPress }, count);
+}
+const Title = ({ title })=>React.createElement('h1', null, title);
+const Label = ({ text })=>{
+  _s1();
+  return React.createElement('span', { style: useTheme() }, text);
+};
+const Header = memo(()=>React.createElement(Title, { title: 'Header' }));
+class App extends React.Component {
+  render() {
+    return React.createElement(Counter);
+  }
+}
+const formatCount = (count)=>count.toString();
+global.__modules.esm("test.js", { Counter, Header, default: App, formatCount }, {
+  deps: ["./hooks", "react"],
+  externals: []
+});
+_s(Counter, "useState{[count, setCount](0)}\nuseTheme{theme}", false, ()=>[useTheme]);
+_s1(Label, "useTheme{}", false, ()=>[useTheme]);
+$RefreshReg$(Counter, "test.js Counter");
+$RefreshReg$(Title, "test.js Title");
+$RefreshReg$(Label, "test.js Label");
+$RefreshReg$(Header, "test.js Header");
+$RefreshReg$(App, "test.js App");
+if (global.__modules.isRefreshBoundary("test.js")) {
+  global.__modules.hot("test.js").accept();
+} else if (global.__modules.hot("test.js").data.__refreshed) {
+  global.__modules.hot("test.js").invalidate();
+}
+global.__modules.hot("test.js").dispose((data)=>{
+  data.__refreshed = true;
+});
@@ -0,0 +1,6 @@
+import { Button } from '../relative/components/Button';
+import { noop } from '../relative/utils';
+
+export function Component() {
+  return Button({ onPress: noop });
+}
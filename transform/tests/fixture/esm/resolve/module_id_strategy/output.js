@@ -0,0 +1,12 @@
+const ____relative_components_Button = global.__modules.import("Button-3c5c990d");
+const ____relative_utils = global.__modules.import("index-d6c4a54f");
+const Button = ____relative_components_Button.Button;
+const noop = ____relative_utils.noop;
+function Component() {
+  return Button({ onPress: noop });
+}
+global.__modules.esm("input-549bb14c", { Component }, {
+  deps: ["Button-3c5c990d", "index-d6c4a54f"],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,6 @@
+import React from 'react';
+import { Lib } from 'lib';
+import Legacy from 'legacy';
+import { format } from '#internal/format';
+
+export const App = () => React.createElement(Lib, { legacy: Legacy, format });
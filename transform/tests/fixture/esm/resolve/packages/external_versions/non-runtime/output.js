@@ -0,0 +1,16 @@
+import * as __external from "react";
+global.__modules.external("react", __external, { version: "18.2.0" });
+import * as __external1 from "lib";
+global.__modules.external("lib", __external1, { version: "1.2.3" });
+import * as __external2 from "legacy";
+global.__modules.external("legacy", __external2, { version: "0.1.0" });
+import React from 'react';
+import { Lib } from 'lib';
+import Legacy from 'legacy';
+import { format } from '#internal/format';
+export const App = () => React.createElement(Lib, { legacy: Legacy, format });
+global.__modules.esm("test.js", { App }, {
+  deps: ["tests/fixture/esm/resolve/packages/src/internal/format.js"],
+  externals: ["legacy", "lib", "react"],
+  boundary: "component"
+});
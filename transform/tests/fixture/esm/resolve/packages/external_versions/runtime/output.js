@@ -0,0 +1,14 @@
+const _legacy = global.__modules.external("legacy", void 0, { requiredVersion: "~0.1.0" });
+const _lib = global.__modules.external("lib", void 0, { requiredVersion: "^1.0.0" });
+const _react = global.__modules.external("react", void 0, { requiredVersion: "^18.0.0" });
+const __internal_format = global.__modules.import("tests/fixture/esm/resolve/packages/src/internal/format.js");
+const React = _react.default;
+const Lib = _lib.Lib;
+const Legacy = _legacy.default;
+const format = __internal_format.format;
+const App = () => React.createElement(Lib, { legacy: Legacy, format });
+global.__modules.esm("test.js", { App }, {
+  deps: ["tests/fixture/esm/resolve/packages/src/internal/format.js"],
+  externals: ["legacy", "lib", "react"],
+  boundary: "component"
+});
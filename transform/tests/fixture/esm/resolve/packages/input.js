@@ -0,0 +1,9 @@
+import { Lib } from 'lib';
+import { Lib as Sub } from 'lib/sub';
+import { Lib as Feature } from 'lib/features/a';
+import Legacy from 'legacy';
+import Pkg from '@scope/pkg';
+import { format } from '#internal/format';
+import platform from '#platform';
+
+export const App = () => format([Lib, Sub, Feature, Legacy, Pkg, platform]);
@@ -0,0 +1,28 @@
+const __scope_pkg = global.__modules.import("tests/fixture/esm/resolve/packages/node_modules/@scope/pkg/index.js");
+const _legacy = global.__modules.import("tests/fixture/esm/resolve/packages/node_modules/legacy/es/main.js");
+const _lib_features_a = global.__modules.import("tests/fixture/esm/resolve/packages/node_modules/lib/dist/features/a.js");
+const _lib = global.__modules.import("tests/fixture/esm/resolve/packages/node_modules/lib/dist/index.native.js");
+const _lib_sub = global.__modules.import("tests/fixture/esm/resolve/packages/node_modules/lib/dist/sub.js");
+const __internal_format = global.__modules.import("tests/fixture/esm/resolve/packages/src/internal/format.js");
+const __platform = global.__modules.import("tests/fixture/esm/resolve/packages/src/platform.native.js");
+const Lib = _lib.Lib;
+const Sub = _lib_sub.Lib;
+const Feature = _lib_features_a.Lib;
+const Legacy = _legacy.default;
+const Pkg = __scope_pkg.default;
+const format = __internal_format.format;
+const platform = __platform.default;
+const App = () => format([Lib, Sub, Feature, Legacy, Pkg, platform]);
+global.__modules.esm("test.js", { App }, {
+  deps: [
+    "tests/fixture/esm/resolve/packages/node_modules/@scope/pkg/index.js",
+    "tests/fixture/esm/resolve/packages/node_modules/legacy/es/main.js",
+    "tests/fixture/esm/resolve/packages/node_modules/lib/dist/features/a.js",
+    "tests/fixture/esm/resolve/packages/node_modules/lib/dist/index.native.js",
+    "tests/fixture/esm/resolve/packages/node_modules/lib/dist/sub.js",
+    "tests/fixture/esm/resolve/packages/src/internal/format.js",
+    "tests/fixture/esm/resolve/packages/src/platform.native.js"
+  ],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,8 @@
+import { Button } from './components/Button';
+import { noop } from './utils';
+import { Missing } from '../missing';
+import React from 'react';
+
+export function Component() {
+  return null;
+}
@@ -0,0 +1,21 @@
+const ____missing = global.__modules.import("../missing");
+const _react = global.__modules.import("react");
+const ___components_Button = global.__modules.import("tests/fixture/esm/resolve/relative/components/Button.tsx");
+const ___utils = global.__modules.import("tests/fixture/esm/resolve/relative/utils/index.ts");
+const Button = ___components_Button.Button;
+const noop = ___utils.noop;
+const Missing = ____missing.Missing;
+const React = _react.default;
+function Component() {
+  return null;
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: [
+    "../missing",
+    "react",
+    "tests/fixture/esm/resolve/relative/components/Button.tsx",
+    "tests/fixture/esm/resolve/relative/utils/index.ts"
+  ],
+  externals: [],
+  boundary: "component"
+});
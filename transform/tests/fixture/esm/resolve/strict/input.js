@@ -0,0 +1,6 @@
+import { Button } from '../relative/components/Button';
+import { Missing } from './Missing';
+
+export function Component() {
+  return Button(Missing);
+}
@@ -0,0 +1,12 @@
+const ___Missing = global.__modules.import("./Missing");
+const ____relative_components_Button = global.__modules.import("tests/fixture/esm/resolve/relative/components/Button.tsx");
+const Button = ____relative_components_Button.Button;
+const Missing = ___Missing.Missing;
+function Component() {
+  return Button(Missing);
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: ["./Missing", "tests/fixture/esm/resolve/relative/components/Button.tsx"],
+  externals: [],
+  boundary: "component"
+});
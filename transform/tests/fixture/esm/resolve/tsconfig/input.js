@@ -0,0 +1,8 @@
+import { Button } from '@app/components/Button';
+import { useFoo } from '@app/hooks';
+import { useBar } from 'hooks';
+import React from 'react';
+
+export function Component() {
+  return null;
+}
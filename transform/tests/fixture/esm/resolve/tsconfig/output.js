@@ -0,0 +1,19 @@
+const _react = global.__modules.import("react");
+const __app_components_Button = global.__modules.import("tests/fixture/esm/resolve/tsconfig/src/components/Button.tsx");
+const __app_hooks = global.__modules.import("tests/fixture/esm/resolve/tsconfig/src/hooks/index.ts");
+const Button = __app_components_Button.Button;
+const useFoo = __app_hooks.useFoo;
+const useBar = __app_hooks.useBar;
+const React = _react.default;
+function Component() {
+  return null;
+}
+global.__modules.esm("test.js", { Component }, {
+  deps: [
+    "react",
+    "tests/fixture/esm/resolve/tsconfig/src/components/Button.tsx",
+    "tests/fixture/esm/resolve/tsconfig/src/hooks/index.ts"
+  ],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,10 @@
+import React, { useState } from 'react';
+import { View } from 'react-native';
+import { foo, bar } from './utils';
+
+export function App() {
+  const [count] = useState(foo + bar);
+  return React.createElement(View, null, count);
+}
+
+export * from './components';
@@ -0,0 +1,18 @@
+const ___components = global.__modules.import("./components");
+const ___utils = global.__modules.import("./utils");
+const _react = global.__modules.external("react");
+const _react_native = global.__modules.external("react-native");
+const React = _react.default;
+const useState = _react.useState;
+const View = _react_native.View;
+const foo = ___utils.foo;
+const bar = ___utils.bar;
+const __re_export_all = global.__modules.helpers.asWildcard(___components);
+function App() {
+  const [count] = useState(foo + bar);
+  return React.createElement(View, null, count);
+}
+global.__modules.esm("test.js", { App }, {
+  deps: ["./components", "./utils"],
+  externals: ["react", "react-native"]
+}, __re_export_all);
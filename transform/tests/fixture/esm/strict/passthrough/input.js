@@ -0,0 +1,5 @@
+import { jsx as _jsx } from 'react/jsx-runtime';
+import routes from 'virtual:routes';
+import { Button } from './Button';
+
+export const App = () => _jsx(Button, { routes });
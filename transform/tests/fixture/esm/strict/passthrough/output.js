@@ -0,0 +1,10 @@
+import { jsx as _jsx } from 'react/jsx-runtime';
+import routes from 'virtual:routes';
+const ___Button = global.__modules.import("./Button");
+const Button = ___Button.Button;
+const App = ()=>_jsx(Button, { routes });
+global.__modules.esm("test.js", { App }, {
+  deps: ["./Button"],
+  externals: [],
+  boundary: "component"
+});
@@ -0,0 +1,149 @@
+use swc_core::{
+    common::{sync::Lrc, FileName, Globals, SourceMap, GLOBALS},
+    ecma::{ast::EsVersion, ast::Module},
+};
+use swc_ecma_parser::{parse_file_as_module, EsConfig, Syntax};
+use swc_global_module::{ExportChange, ExportKind, ModuleSignature, SignatureDiff};
+
+fn signature(src: &str) -> ModuleSignature {
+    GLOBALS.set(&Globals::new(), || ModuleSignature::new(&parse(src)))
+}
+
+fn parse(src: &str) -> Module {
+    let cm = Lrc::<SourceMap>::default();
+    let fm = cm.new_source_file(FileName::Anon, src.into());
+    parse_file_as_module(
+        &fm,
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .expect("failed to parse the module")
+}
+
+#[test]
+fn module_signature() {
+    let signature = signature(
+        r#"
+        import { useState } from 'react';
+        const { format } = require('./format');
+        export * from './theme';
+        export { Button } from './Button';
+        export const label = format('label');
+        export default function App() {
+          const [count] = useState(0);
+          return <Button>{count}</Button>;
+        }
+        "#,
+    );
+
+    assert_eq!(
+        signature.exports.into_iter().collect::<Vec<_>>(),
+        vec![
+            (String::from("Button"), ExportKind::ReExport),
+            (String::from("default"), ExportKind::Component),
+            (String::from("label"), ExportKind::Value),
+        ]
+    );
+    assert_eq!(
+        signature.export_all.into_iter().collect::<Vec<_>>(),
+        vec!["./theme"]
+    );
+    assert_eq!(
+        signature.deps.into_iter().collect::<Vec<_>>(),
+        vec!["./Button", "./format", "./theme", "react"]
+    );
+}
+
+#[test]
+fn module_signature_components() {
+    let signature = signature(
+        r#"
+        import { memo } from 'react';
+        import { createConfig } from './config';
+        export class NotFoundError extends Error {}
+        export const CONFIG = createConfig();
+        export const Input = memo((props) => <input {...props} />);
+        export const renderItem = () => <li />;
+        export default memo(Input);
+        "#,
+    );
+
+    assert_eq!(
+        signature.exports.into_iter().collect::<Vec<_>>(),
+        vec![
+            (String::from("CONFIG"), ExportKind::Value),
+            (String::from("Input"), ExportKind::Component),
+            (String::from("NotFoundError"), ExportKind::Value),
+            (String::from("default"), ExportKind::Component),
+            (String::from("renderItem"), ExportKind::Value),
+        ]
+    );
+}
+
+#[test]
+fn signature_diff() {
+    let prev = signature(
+        r#"
+        import { format } from './format';
+        export const label = format('label');
+        export default function App() {
+          return <div />;
+        }
+        "#,
+    );
+
+    let diff = prev.diff(&signature(
+        r#"
+        import { format } from './format';
+        export const label = format('label!');
+        export default function App() {
+          return <div />;
+        }
+        "#,
+    ));
+    assert!(diff.is_empty());
+    assert!(!diff.requires_reload());
+
+    let diff = prev.diff(&signature(
+        r#"
+        import { format } from './utils';
+        export const title = format('title');
+        export default function App() {
+          return <div />;
+        }
+        "#,
+    ));
+    assert_eq!(
+        diff,
+        SignatureDiff {
+            added: vec![String::from("title")],
+            removed: vec![String::from("label")],
+            added_deps: vec![String::from("./utils")],
+            removed_deps: vec![String::from("./format")],
+            ..Default::default()
+        }
+    );
+    assert!(diff.requires_reload());
+
+    let diff = prev.diff(&signature(
+        r#"
+        import { format } from './format';
+        export const label = format('label');
+        export default format;
+        "#,
+    ));
+    assert_eq!(
+        diff.changed,
+        vec![ExportChange {
+            name: String::from("default"),
+            prev: ExportKind::Component,
+            next: ExportKind::Value,
+        }]
+    );
+    assert!(diff.requires_reload());
+}